@@ -1,3 +1,8 @@
+use crate::input_parsing::{to_vec, Input::Raw, Separator::Newline};
+use crate::solution::Solution;
+use anyhow::Result;
+use std::fmt::Display;
+
 /// Counts the number of increasing pairs in windowed sums of given data.
 ///
 /// # Arguments
@@ -35,12 +40,28 @@ pub fn count_of_increasing_pairs_in_windowed_sums(data: &[i32], window_size: usi
 
     count_increasing
 }
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(to_vec(Raw(input), Newline))
+    }
+
+    fn part1(numbers: Self::Input<'_>) -> Result<impl Display> {
+        Ok(count_of_increasing_pairs_in_windowed_sums(&numbers, 1))
+    }
+
+    fn part2(numbers: Self::Input<'_>) -> Result<impl Display> {
+        Ok(count_of_increasing_pairs_in_windowed_sums(&numbers, 3))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::to_vec;
-    use crate::input_parsing::Input::{Path, Raw};
-    use crate::input_parsing::Separator::Newline;
 
     #[test]
     fn test_1_sample() {
@@ -63,15 +84,6 @@ mod tests {
         assert_eq!(count, 7);
     }
 
-    #[test]
-    fn test_1() {
-        let numbers = to_vec(Path("input/2021/01.txt"), Newline);
-
-        let count = count_of_increasing_pairs_in_windowed_sums(&numbers, 1);
-
-        assert_eq!(count, 1583);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = "
@@ -92,13 +104,4 @@ mod tests {
 
         assert_eq!(count, 5);
     }
-
-    #[test]
-    fn test_2() {
-        let numbers: Vec<i32> = to_vec(Path("input/2021/01.txt"), Newline);
-
-        let count = count_of_increasing_pairs_in_windowed_sums(&numbers, 3);
-
-        assert_eq!(count, 1627);
-    }
 }
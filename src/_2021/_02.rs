@@ -1,3 +1,8 @@
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::Result;
+use std::fmt::Display;
+
 #[derive(Debug)]
 pub enum Command {
     Forward(i32),
@@ -104,11 +109,27 @@ pub fn calculate_aim_and_distance(commands: Vec<Command>) -> i32 {
     horizontal_position * vertical_depth
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(Command::parse_batch(to_lines(Raw(input))))
+    }
+
+    fn part1(commands: Self::Input<'_>) -> Result<impl Display> {
+        Ok(calculate_distance(commands))
+    }
+
+    fn part2(commands: Self::Input<'_>) -> Result<impl Display> {
+        Ok(calculate_aim_and_distance(commands))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::to_lines;
-    use crate::input_parsing::Input::{Path, Raw};
 
     #[test]
     fn test_1_sample() {
@@ -127,16 +148,6 @@ mod tests {
         assert_eq!(result, 150);
     }
 
-    #[test]
-    fn test_1() {
-        let input = to_lines(Path("input/2021/02.txt"));
-        let commands = Command::parse_batch(input);
-
-        let result = calculate_distance(commands);
-
-        assert_eq!(result, 2_150_351);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = to_lines(Raw("
@@ -153,14 +164,4 @@ mod tests {
 
         assert_eq!(result, 900);
     }
-
-    #[test]
-    fn test_2() {
-        let input = to_lines(Path("input/2021/02.txt"));
-        let commands = Command::parse_batch(input);
-
-        let result = calculate_aim_and_distance(commands);
-
-        assert_eq!(result, 1_842_742_223);
-    }
 }
@@ -1,4 +1,8 @@
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::{ensure, Result};
 use std::collections::HashMap;
+use std::fmt::Display;
 
 pub fn find_all_most_common_bits(binary_report: &Vec<String>) -> String {
     let mut freq_of_ones = HashMap::new();
@@ -40,7 +44,8 @@ pub fn find_component_rating(mut binary_report: Vec<String>, bit_criteria: BitCr
     let mut freq1 = 0;
     let mut position = 0;
 
-    while binary_report.len() != 1 {
+    // an empty report has nothing left to narrow down
+    while binary_report.len() > 1 {
         for s in &binary_report {
             match s.chars().nth(position) {
                 Some('0') => freq0 += 1,
@@ -89,10 +94,33 @@ pub fn binary_str_to_decimal(binary: &str) -> i32 {
     i32::from_str_radix(binary, 2).expect("Failed to convert binary string to decimal")
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let report: Vec<String> = to_lines(Raw(input)).collect();
+        ensure!(!report.is_empty(), "the report is empty");
+        Ok(report)
+    }
+
+    fn part1(report: Self::Input<'_>) -> Result<impl Display> {
+        let gamma_rate = find_all_most_common_bits(&report);
+        let epsilon_rate = flip_binary_str_bits(&gamma_rate);
+        Ok(binary_str_to_decimal(&gamma_rate) * binary_str_to_decimal(&epsilon_rate))
+    }
+
+    fn part2(report: Self::Input<'_>) -> Result<impl Display> {
+        let oxygen = find_component_rating(report.clone(), BitCriteria::Oxygen);
+        let co2 = find_component_rating(report, BitCriteria::CO2);
+        Ok(binary_str_to_decimal(&oxygen) * binary_str_to_decimal(&co2))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::{to_lines, Input::*};
 
     #[test]
     fn test_1_sample() {
@@ -121,19 +149,6 @@ mod tests {
         assert_eq!(power_consumption, 198);
     }
 
-    #[test]
-    fn test_1() {
-        let input: Vec<String> = to_lines(Path("input/2021/03.txt")).collect();
-
-        let gamma_rate = find_all_most_common_bits(&input);
-        let epsilon_rate = flip_binary_str_bits(&gamma_rate);
-
-        let power_consumption =
-            binary_str_to_decimal(&gamma_rate) * binary_str_to_decimal(&epsilon_rate);
-
-        assert_eq!(power_consumption, 3_633_500);
-    }
-
     #[test]
     fn test_2_sample() {
         let input: Vec<String> = to_lines(Raw("
@@ -159,16 +174,4 @@ mod tests {
 
         assert_eq!(life_support_rating, 230);
     }
-
-    #[test]
-    fn test_2() {
-        let input: Vec<String> = to_lines(Path("input/2021/03.txt")).collect();
-
-        let oxygen_generator_rating = find_component_rating(input.clone(), BitCriteria::Oxygen);
-        let co2_scrubber_rating = find_component_rating(input, BitCriteria::CO2);
-        let life_support_rating = binary_str_to_decimal(&oxygen_generator_rating)
-            * binary_str_to_decimal(&co2_scrubber_rating);
-
-        assert_eq!(life_support_rating, 4_550_283);
-    }
 }
@@ -1,5 +1,9 @@
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use std::collections::HashSet;
+use std::fmt::Display;
 
 #[derive(Debug)]
 pub struct BingoBoard([[BingoCell; 5]; 5]);
@@ -113,10 +117,34 @@ pub fn play_bingo(calls: Vec<i32>, mut boards: Vec<BingoBoard>) -> Vec<i32> {
 
     winning_scores
 }
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<i32>, Vec<BingoBoard>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_calls_and_bingo_boards(to_lines(Raw(input))))
+    }
+
+    fn part1((calls, boards): Self::Input<'_>) -> Result<impl Display> {
+        play_bingo(calls, boards)
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("no board won"))
+    }
+
+    fn part2((calls, boards): Self::Input<'_>) -> Result<impl Display> {
+        play_bingo(calls, boards)
+            .last()
+            .copied()
+            .ok_or_else(|| anyhow!("no board won"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::{to_lines, Input::*};
     #[test]
     fn test_1_sample() {
         let input = to_lines(Raw("
@@ -148,17 +176,6 @@ mod tests {
         assert_eq!(*winning_scores.first().unwrap(), 4_512);
     }
 
-    #[test]
-    fn test_1() {
-        let input = to_lines(Path("input/2021/04.txt"));
-
-        let (calls, boards) = parse_calls_and_bingo_boards(input);
-
-        let winning_scores = play_bingo(calls, boards);
-
-        assert_eq!(*winning_scores.first().unwrap(), 8_136);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = to_lines(Raw("
@@ -189,15 +206,4 @@ mod tests {
 
         assert_eq!(*winning_scores.last().unwrap(), 1_924);
     }
-
-    #[test]
-    fn test_2() {
-        let input = to_lines(Path("input/2021/04.txt"));
-
-        let (calls, boards) = parse_calls_and_bingo_boards(input);
-
-        let winning_scores = play_bingo(calls, boards);
-
-        assert_eq!(*winning_scores.last().unwrap(), 12_738);
-    }
 }
@@ -1,30 +1,28 @@
+use crate::grid::Point;
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::Result;
 use std::collections::HashMap;
+use std::fmt::Display;
 
-#[derive(Hash, Eq, PartialEq, Copy, Clone)]
-pub struct Point {
-    x: i32,
-    y: i32,
+pub fn parse_line_to_point(point_str: &str) -> Option<Point> {
+    let (x_str, y_str) = point_str.split_once(',')?;
+    let x = x_str.trim().parse::<i32>().ok()?;
+    let y = y_str.trim().parse::<i32>().ok()?;
+    Some(Point { x, y })
 }
-impl Point {
-    pub fn parse_line_to_point(point_str: &str) -> Option<Self> {
-        let (x_str, y_str) = point_str.split_once(',')?;
-        let x = x_str.trim().parse::<i32>().ok()?;
-        let y = y_str.trim().parse::<i32>().ok()?;
-        Some(Point { x, y })
-    }
 
-    pub fn parse_line_to_pair(line: &str) -> Option<(Self, Self)> {
-        let (start_str, end_str) = line.split_once("->")?;
-        let start_point = Self::parse_line_to_point(start_str)?;
-        let end_point = Self::parse_line_to_point(end_str)?;
-        Some((start_point, end_point))
-    }
+pub fn parse_line_to_pair(line: &str) -> Option<(Point, Point)> {
+    let (start_str, end_str) = line.split_once("->")?;
+    let start_point = parse_line_to_point(start_str)?;
+    let end_point = parse_line_to_point(end_str)?;
+    Some((start_point, end_point))
+}
 
-    pub fn parse_batch(lines: impl Iterator<Item = String>) -> impl Iterator<Item = (Self, Self)> {
-        lines
-            .into_iter()
-            .filter_map(|line| Self::parse_line_to_pair(&line))
-    }
+pub fn parse_batch(lines: impl Iterator<Item = String>) -> impl Iterator<Item = (Point, Point)> {
+    lines
+        .into_iter()
+        .filter_map(|line| parse_line_to_pair(&line))
 }
 
 #[derive(Copy, Clone)]
@@ -42,23 +40,11 @@ pub fn plot_points(
         if matches!(plot_diagonals, Diagonals::Exclude) && start.x != end.x && start.y != end.y {
             continue;
         }
-        while start.x != end.x || start.y != end.y {
+        let step = Point::new((end.x - start.x).signum(), (end.y - start.y).signum());
+        while start != end {
             let count = grid.entry(start).or_insert(0);
             *count += 1;
-
-            if start.x < end.x {
-                start.x += 1;
-            }
-            if start.x > end.x {
-                start.x -= 1;
-            }
-
-            if start.y < end.y {
-                start.y += 1;
-            }
-            if start.y > end.y {
-                start.y -= 1;
-            }
+            start = start + step;
         }
         let count = grid.entry(start).or_insert(0);
         *count += 1;
@@ -74,10 +60,34 @@ pub fn count_overlapping_points(grid: HashMap<Point, i32>) -> i32 {
         count
     })
 }
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(Point, Point)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_batch(to_lines(Raw(input))).collect())
+    }
+
+    fn part1(lines: Self::Input<'_>) -> Result<impl Display> {
+        Ok(count_overlapping_points(plot_points(
+            lines.into_iter(),
+            Diagonals::Exclude,
+        )))
+    }
+
+    fn part2(lines: Self::Input<'_>) -> Result<impl Display> {
+        Ok(count_overlapping_points(plot_points(
+            lines.into_iter(),
+            Diagonals::Include,
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::{to_lines, Input::*};
     #[test]
     fn test_1_sample() {
         let input = to_lines(Raw("
@@ -93,20 +103,11 @@ mod tests {
         5,5 -> 8,2
         "));
 
-        let grid = plot_points(Point::parse_batch(input), Diagonals::Exclude);
+        let grid = plot_points(parse_batch(input), Diagonals::Exclude);
 
         assert_eq!(count_overlapping_points(grid), 5);
     }
 
-    #[test]
-    fn test_1() {
-        let input = to_lines(Path("input/2021/05.txt"));
-
-        let grid = plot_points(Point::parse_batch(input), Diagonals::Exclude);
-
-        assert_eq!(count_overlapping_points(grid), 8_111);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = to_lines(Raw("
@@ -122,17 +123,8 @@ mod tests {
         5,5 -> 8,2
         "));
 
-        let grid = plot_points(Point::parse_batch(input), Diagonals::Include);
+        let grid = plot_points(parse_batch(input), Diagonals::Include);
 
         assert_eq!(count_overlapping_points(grid), 12);
     }
-
-    #[test]
-    fn test_2() {
-        let input = to_lines(Path("input/2021/05.txt"));
-
-        let grid = plot_points(Point::parse_batch(input), Diagonals::Include);
-
-        assert_eq!(count_overlapping_points(grid), 22_088);
-    }
 }
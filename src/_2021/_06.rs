@@ -1,3 +1,8 @@
+use crate::input_parsing::{to_vec, Input::Raw, Separator::Comma};
+use crate::solution::Solution;
+use anyhow::Result;
+use std::fmt::Display;
+
 pub fn parse_lantern_fish_histogram(input: &[usize]) -> Vec<u128> {
     input.iter().fold(vec![0; 9], |mut acc, &i| {
         acc[i] += 1;
@@ -20,10 +25,27 @@ pub fn advance_lantern_fish_days(mut hist: Vec<u128>, days: i32) -> u128 {
     hist.iter().sum()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<u128>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_lantern_fish_histogram(&to_vec(Raw(input), Comma)))
+    }
+
+    fn part1(lantern_fish: Self::Input<'_>) -> Result<impl Display> {
+        Ok(advance_lantern_fish_days(lantern_fish, 80))
+    }
+
+    fn part2(lantern_fish: Self::Input<'_>) -> Result<impl Display> {
+        Ok(advance_lantern_fish_days(lantern_fish, 256))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::{to_vec, Input::*, Separator::*};
     #[test]
     fn test_1_sample() {
         let input = to_vec(Raw("3,4,3,1,2"), Comma);
@@ -35,17 +57,6 @@ mod tests {
         assert_eq!(total, 5_934);
     }
 
-    #[test]
-    fn test_1() {
-        let input = to_vec(Path("input/2021/06.txt"), Comma);
-
-        let lantern_fish = parse_lantern_fish_histogram(&input);
-
-        let total = advance_lantern_fish_days(lantern_fish, 80);
-
-        assert_eq!(total, 363_101);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = to_vec(Raw("3,4,3,1,2"), Comma);
@@ -56,15 +67,4 @@ mod tests {
 
         assert_eq!(total, 26_984_457_539);
     }
-
-    #[test]
-    fn test_2() {
-        let input = to_vec(Path("input/2021/06.txt"), Comma);
-
-        let lantern_fish = parse_lantern_fish_histogram(&input);
-
-        let total = advance_lantern_fish_days(lantern_fish, 256);
-
-        assert_eq!(total, 1_644_286_074_024);
-    }
 }
@@ -1,3 +1,9 @@
+use crate::input_parsing::{to_vec, Input::Raw, Separator::Comma};
+use crate::solution::Solution;
+use anyhow::Result;
+use std::convert::identity;
+use std::fmt::Display;
+
 /// Calculates the Nth triangle number.
 ///
 /// A triangle number is the sum of all positive integers up to and including N.
@@ -32,10 +38,28 @@ pub fn find_cheapest_horizontal_position(crabs: &[i32], fuel_calculator: fn(i32)
         .min()
         .unwrap()
 }
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(to_vec(Raw(input), Comma))
+    }
+
+    fn part1(crabs: Self::Input<'_>) -> Result<impl Display> {
+        Ok(find_cheapest_horizontal_position(&crabs, identity))
+    }
+
+    fn part2(crabs: Self::Input<'_>) -> Result<impl Display> {
+        Ok(find_cheapest_horizontal_position(&crabs, triangle_number))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::{to_vec, Input::*, Separator::*};
     use std::convert::identity;
     #[test]
     fn test_1_sample() {
@@ -44,13 +68,6 @@ mod tests {
         assert_eq!(find_cheapest_horizontal_position(&crabs, identity), 37);
     }
 
-    #[test]
-    fn test_1() {
-        let crabs = to_vec(Path("input/2021/07.txt"), Comma);
-
-        assert_eq!(find_cheapest_horizontal_position(&crabs, identity), 348_996);
-    }
-
     #[test]
     fn test_2_sample() {
         let crabs = to_vec(Raw("16,1,2,0,4,2,7,1,2,14"), Comma);
@@ -60,14 +77,4 @@ mod tests {
             168
         );
     }
-
-    #[test]
-    fn test_2() {
-        let crabs = to_vec(Path("input/2021/07.txt"), Comma);
-
-        assert_eq!(
-            find_cheapest_horizontal_position(&crabs, triangle_number),
-            98_231_647
-        );
-    }
 }
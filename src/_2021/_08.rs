@@ -1,4 +1,8 @@
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
+use std::fmt::Display;
 
 pub fn count_1478(input: impl Iterator<Item = String>) -> i32 {
     input
@@ -118,10 +122,28 @@ pub fn determine_output(row: &str) -> i32 {
         .parse::<i32>()
         .unwrap()
 }
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(to_lines(Raw(input)).collect())
+    }
+
+    fn part1(lines: Self::Input<'_>) -> Result<impl Display> {
+        Ok(count_1478(lines.into_iter()))
+    }
+
+    fn part2(lines: Self::Input<'_>) -> Result<impl Display> {
+        Ok(lines.iter().map(|line| determine_output(line)).sum::<i32>())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::{to_lines, Input::*};
 
     #[test]
     fn test_determine_output() {
@@ -173,13 +195,6 @@ mod tests {
         assert_eq!(count_1478(signal), 26);
     }
 
-    #[test]
-    fn test_1() {
-        let input = to_lines(Path("input/2021/08.txt"));
-
-        assert_eq!(count_1478(input), 530);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = to_lines(Raw("
@@ -197,11 +212,4 @@ mod tests {
 
         assert_eq!(input.map(|l| determine_output(&l)).sum::<i32>(), 61_229);
     }
-
-    #[test]
-    fn test_2() {
-        let input = to_lines(Path("input/2021/08.txt"));
-
-        assert_eq!(input.map(|l| determine_output(&l)).sum::<i32>(), 1_051_087);
-    }
 }
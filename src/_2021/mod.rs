@@ -6,3 +6,16 @@ pub mod _05;
 pub mod _06;
 pub mod _07;
 pub mod _08;
+
+use crate::registry::Entry;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new::<_01::Day>(2021, 1),
+    Entry::new::<_02::Day>(2021, 2),
+    Entry::new::<_03::Day>(2021, 3),
+    Entry::new::<_04::Day>(2021, 4),
+    Entry::new::<_05::Day>(2021, 5),
+    Entry::new::<_06::Day>(2021, 6),
+    Entry::new::<_07::Day>(2021, 7),
+    Entry::new::<_08::Day>(2021, 8),
+];
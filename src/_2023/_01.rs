@@ -1,3 +1,8 @@
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
+use std::fmt::Display;
+
 #[derive(Copy, Clone)]
 pub enum ParseMode {
     Literals,
@@ -51,12 +56,32 @@ pub fn parse_batch_calibration_values(
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(to_lines(Raw(input)).collect())
+    }
+
+    fn part1(lines: Self::Input<'_>) -> Result<impl Display> {
+        parse_batch_calibration_values(lines.into_iter(), ParseMode::Literals)
+            .map(|values| values.iter().sum::<i32>())
+            .ok_or_else(|| anyhow!("every line must contain a digit"))
+    }
+
+    fn part2(lines: Self::Input<'_>) -> Result<impl Display> {
+        parse_batch_calibration_values(lines.into_iter(), ParseMode::LettersAndLiterals)
+            .map(|values| values.iter().sum::<i32>())
+            .ok_or_else(|| anyhow!("every line must contain a digit"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ParseMode::*;
     use super::*;
-    use crate::input_parsing::to_lines;
-    use crate::input_parsing::Input::{Path, Raw};
 
     #[test]
     fn test_1_sample() {
@@ -69,13 +94,6 @@ treb7uchet");
         assert_eq!(calibration_values.iter().sum::<i32>(), 142);
     }
 
-    #[test]
-    fn test_1() {
-        let input = Path("input/2023/01.txt");
-        let calibration_values = parse_batch_calibration_values(to_lines(input), Literals).unwrap();
-        assert_eq!(calibration_values.iter().sum::<i32>(), 54_601);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = Raw("\
@@ -90,12 +108,4 @@ zoneight234
             parse_batch_calibration_values(to_lines(input), LettersAndLiterals).unwrap();
         assert_eq!(calibration_values.iter().sum::<i32>(), 281);
     }
-
-    #[test]
-    fn test_2() {
-        let input = Path("input/2023/01.txt");
-        let calibration_values =
-            parse_batch_calibration_values(to_lines(input), LettersAndLiterals).unwrap();
-        assert_eq!(calibration_values.iter().sum::<i32>(), 54_078);
-    }
 }
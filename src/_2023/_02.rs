@@ -1,6 +1,10 @@
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
 use crate::_2023::_02::Color::{Blue, Green, Red};
+use anyhow::{anyhow, Result};
 use std::cmp;
 use std::error::Error;
+use std::fmt::Display;
 use std::str::FromStr;
 
 #[derive(Copy, Clone)]
@@ -112,11 +116,30 @@ pub fn sum_of_powers(games: &[Game]) -> i32 {
     games.iter().map(calculate_power).sum()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_batch_games(to_lines(Raw(input))).ok_or_else(|| anyhow!("failed to parse games"))
+    }
+
+    fn part1(games: Self::Input<'_>) -> Result<impl Display> {
+        let max = "14 blue, 13 green, 12 red"
+            .parse()
+            .map_err(|()| anyhow!("failed to parse the bag contents"))?;
+        Ok(sum_impossible_game_ids(&games, max))
+    }
+
+    fn part2(games: Self::Input<'_>) -> Result<impl Display> {
+        Ok(sum_of_powers(&games))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::to_lines;
-    use crate::input_parsing::Input::{Path, Raw};
 
     #[test]
     fn test_calculate_power() {
@@ -182,20 +205,6 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\
         assert_eq!(sum_impossible_game_ids(&games, max), 8);
     }
 
-    #[test]
-    fn test_1() {
-        let input = to_lines(Path("input/2023/02.txt"));
-        let max = Set {
-            blue_count: 14,
-            green_count: 13,
-            red_count: 12,
-        };
-
-        let games = parse_batch_games(input).unwrap();
-
-        assert_eq!(sum_impossible_game_ids(&games, max), 2_239);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = to_lines(Raw("\
@@ -211,14 +220,4 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\
 
         assert_eq!(power_sum, 2_286);
     }
-
-    #[test]
-    fn test_2() {
-        let input = to_lines(Path("input/2023/02.txt"));
-
-        let games = parse_batch_games(input).unwrap();
-        let power_sum = sum_of_powers(&games);
-
-        assert_eq!(power_sum, 83_435);
-    }
 }
@@ -1,10 +1,9 @@
+use crate::grid::Point;
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use std::collections::{HashMap, HashSet};
-
-#[derive(Eq, PartialEq, Debug, Hash, Clone, Copy)]
-pub struct Point {
-    x: i32,
-    y: i32,
-}
+use std::fmt::Display;
 
 #[derive(Debug)]
 pub struct Schematic {
@@ -155,11 +154,27 @@ impl Schematic {
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Schematic::from_lines(to_lines(Raw(input))).ok_or_else(|| anyhow!("schematic is empty"))
+    }
+
+    fn part1(schematic: Self::Input<'_>) -> Result<impl Display> {
+        Ok(schematic.collect_part_numbers().iter().sum::<i32>())
+    }
+
+    fn part2(schematic: Self::Input<'_>) -> Result<impl Display> {
+        Ok(schematic.sum_of_gear_ratios())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::to_lines;
-    use crate::input_parsing::Input::{Path, Raw};
 
     #[test]
     fn test_1_sample() {
@@ -180,18 +195,6 @@ mod tests {
         assert_eq!(schematic.collect_part_numbers().iter().sum::<i32>(), 4_361);
     }
 
-    #[test]
-    fn test_1() {
-        let input = Path("input/2023/03.txt");
-
-        let schematic = Schematic::from_lines(to_lines(input)).unwrap();
-
-        assert_eq!(
-            schematic.collect_part_numbers().iter().sum::<i32>(),
-            539_590
-        );
-    }
-
     #[test]
     fn test_2_sample() {
         let input = Raw("\
@@ -211,13 +214,4 @@ mod tests {
 
         assert_eq!(schematic.sum_of_gear_ratios(), 467_835);
     }
-
-    #[test]
-    fn test_2() {
-        let input = Path("input/2023/03.txt");
-
-        let schematic = Schematic::from_lines(to_lines(input)).unwrap();
-
-        assert_eq!(schematic.sum_of_gear_ratios(), 80_703_636);
-    }
 }
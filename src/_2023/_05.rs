@@ -1,6 +1,10 @@
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::error::Error;
+use std::fmt::Display;
 use std::ops::Range;
 use std::str::FromStr;
 
@@ -123,11 +127,27 @@ impl Almanac {
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Almanac::new(to_lines(Raw(input))).ok_or_else(|| anyhow!("failed to parse almanac"))
+    }
+
+    fn part1(almanac: Self::Input<'_>) -> Result<impl Display> {
+        Ok(almanac.lowest_location())
+    }
+
+    fn part2(almanac: Self::Input<'_>) -> Result<impl Display> {
+        Ok(almanac.lowest_location_over_ranges())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::to_lines;
-    use crate::input_parsing::Input::{Path, Raw};
 
     #[test]
     fn test_1_sample() {
@@ -172,15 +192,6 @@ humidity-to-location map:
         assert_eq!(almanac.lowest_location(), 35);
     }
 
-    #[test]
-    fn test_1() {
-        let input = Path("input/2023/05.txt");
-
-        let almanac = Almanac::new(to_lines(input)).unwrap();
-
-        assert_eq!(almanac.lowest_location(), 107_430_936);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = Raw("\
@@ -223,14 +234,4 @@ humidity-to-location map:
 
         assert_eq!(almanac.lowest_location_over_ranges(), 46);
     }
-
-    #[test]
-    #[ignore]
-    fn test_2() {
-        let input = Path("input/2023/05.txt");
-
-        let almanac = Almanac::new(to_lines(input)).unwrap();
-
-        assert_eq!(almanac.lowest_location_over_ranges(), 23_738_616);
-    }
 }
@@ -1,3 +1,8 @@
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
+use std::fmt::Display;
+
 #[derive(Debug, PartialOrd, PartialEq)]
 pub struct Race {
     duration: u64,
@@ -102,11 +107,34 @@ impl Race {
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(to_lines(Raw(input)).collect())
+    }
+
+    fn part1(lines: Self::Input<'_>) -> Result<impl Display> {
+        let races = Race::from_iterator(lines.into_iter())
+            .ok_or_else(|| anyhow!("failed to parse races"))?;
+        Ok(races
+            .iter()
+            .map(Race::winning_permutations)
+            .product::<u64>())
+    }
+
+    fn part2(lines: Self::Input<'_>) -> Result<impl Display> {
+        let race = Race::one_from_iterator(lines.into_iter())
+            .ok_or_else(|| anyhow!("failed to parse race"))?;
+        Ok(race.winning_permutations())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::to_lines;
-    use crate::input_parsing::Input::{Path, Raw};
 
     #[test]
     fn test_from_iterator() {
@@ -164,21 +192,6 @@ Distance:  9  40  200
         );
     }
 
-    #[test]
-    fn test_1() {
-        let input = Path("input/2023/06.txt");
-
-        let races = Race::from_iterator(to_lines(input)).unwrap();
-
-        assert_eq!(
-            races
-                .iter()
-                .map(Race::winning_permutations)
-                .product::<u64>(),
-            741_000
-        );
-    }
-
     #[test]
     fn test_2_sample() {
         let input = Raw("\
@@ -190,13 +203,4 @@ Distance:  9  40  200
 
         assert_eq!(race.winning_permutations(), 71_503);
     }
-
-    #[test]
-    fn test_2() {
-        let input = Path("input/2023/06.txt");
-
-        let race = Race::one_from_iterator(to_lines(input)).unwrap();
-
-        assert_eq!(race.winning_permutations(), 38_220_708);
-    }
 }
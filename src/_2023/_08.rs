@@ -1,8 +1,14 @@
+use crate::graph::Graph;
+use crate::input_parsing::{to_lines, Input::Raw};
+use crate::math;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::fmt::Display;
 
-pub fn parse(i: &[String]) -> (Vec<char>, HashMap<&str, (&str, &str)>) {
+/// Every node has an edge to its left and then its right node.
+pub fn parse(i: &[String]) -> (Vec<char>, Graph<'_>) {
     let mut i = i.iter();
     let instructions = i
         .next()
@@ -10,19 +16,19 @@ pub fn parse(i: &[String]) -> (Vec<char>, HashMap<&str, (&str, &str)>) {
         .chars()
         .collect();
 
-    let graph = i
-        .map(|line| {
-            let (key, values) = line.split_once('=').expect("line to be split on =");
+    let mut graph = Graph::new();
+    for line in i {
+        let (key, values) = line.split_once('=').expect("line to be split on =");
 
-            let (left, right) = values.split_once(',').expect("line has values");
-            let (left, right) = (
-                left.trim().trim_start_matches('('),
-                right.trim().trim_end_matches(')'),
-            );
+        let (left, right) = values.split_once(',').expect("line has values");
+        let (left, right) = (
+            left.trim().trim_start_matches('('),
+            right.trim().trim_end_matches(')'),
+        );
 
-            (key.trim(), (left, right))
-        })
-        .collect();
+        graph.add_edge(key.trim(), left);
+        graph.add_edge(key.trim(), right);
+    }
 
     (instructions, graph)
 }
@@ -35,26 +41,18 @@ pub fn ends_with_z(current: &str) -> bool {
     current.ends_with('Z')
 }
 
-pub fn steps_to_done<F>(
-    instructions: &[char],
-    graph: &HashMap<&str, (&str, &str)>,
-    start: &str,
-    is_done: F,
-) -> u64
+pub fn steps_to_done<F>(instructions: &[char], graph: &Graph, start: &str, is_done: F) -> u64
 where
     F: Fn(&str) -> bool,
 {
+    let start = graph.id(start).expect("start should be a node");
     instructions
         .iter()
         .cycle()
-        .fold_while((start, 0), |(mut current, steps), instruction| {
-            let (left, right) = graph.get(current).expect("all nodes should have an entry");
-            if *instruction == 'L' {
-                current = left;
-            } else {
-                current = right;
-            }
-            if is_done(current) {
+        .fold_while((start, 0), |(current, steps), instruction| {
+            let (left, right) = (graph.neighbors(current)[0], graph.neighbors(current)[1]);
+            let current = if *instruction == 'L' { left } else { right };
+            if is_done(graph.name(current)) {
                 Done((current, steps + 1))
             } else {
                 Continue((current, steps + 1))
@@ -64,40 +62,43 @@ where
         .1
 }
 
-fn gcd(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
-    }
-}
-
-fn lcm(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
-}
-
-pub fn lcm_of_list(numbers: &[u64]) -> u64 {
-    numbers.iter().fold(1, |acc, &num| lcm(acc, num))
-}
-
-pub fn ghost_steps_to_z(instructions: &[char], graph: &HashMap<&str, (&str, &str)>) -> u64 {
+/// `None` if the ghosts don't all end up on a Z before the step count overflows.
+pub fn ghost_steps_to_z(instructions: &[char], graph: &Graph) -> Option<u64> {
     let ghosts: Vec<&str> = graph
-        .keys()
+        .nodes()
+        .map(|id| graph.name(id))
         .filter(|key| key.ends_with('A'))
-        .copied()
         .collect();
     let steps: Vec<u64> = ghosts
         .iter()
         .map(|ghost| steps_to_done(instructions, graph, ghost, ends_with_z))
         .collect();
-    lcm_of_list(&steps)
+    math::lcm_of(&steps)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(to_lines(Raw(input)).collect())
+    }
+
+    fn part1(lines: Self::Input<'_>) -> Result<impl Display> {
+        let (instructions, graph) = parse(&lines);
+        Ok(steps_to_done(&instructions, &graph, "AAA", is_zzz))
+    }
+
+    fn part2(lines: Self::Input<'_>) -> Result<impl Display> {
+        let (instructions, graph) = parse(&lines);
+        ghost_steps_to_z(&instructions, &graph).ok_or_else(|| anyhow!("the ghosts never meet"))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input_parsing::to_lines;
-    use crate::input_parsing::Input::{Path, Raw};
 
     #[test]
     fn test_1_sample() {
@@ -130,16 +131,6 @@ ZZZ = (ZZZ, ZZZ)
         assert_eq!(steps_to_done(&second.0, &second.1, "AAA", is_zzz), 6);
     }
 
-    #[test]
-    fn test_1() {
-        let input = Path("input/2023/08.txt");
-
-        let input = to_lines(input).collect::<Vec<_>>();
-        let graph = parse(&input);
-
-        assert_eq!(steps_to_done(&graph.0, &graph.1, "AAA", is_zzz), 19_241);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = Raw("\
@@ -158,16 +149,6 @@ XXX = (XXX, XXX)
         let input = to_lines(input).collect::<Vec<_>>();
         let graph = parse(&input);
 
-        assert_eq!(ghost_steps_to_z(&graph.0, &graph.1), 6);
-    }
-
-    #[test]
-    fn test_2() {
-        let input = Path("input/2023/08.txt");
-
-        let input = to_lines(input).collect::<Vec<_>>();
-        let graph = parse(&input);
-
-        assert_eq!(ghost_steps_to_z(&graph.0, &graph.1), 9_606_140_307_013);
+        assert_eq!(ghost_steps_to_z(&graph.0, &graph.1), Some(6));
     }
 }
@@ -1,3 +1,7 @@
+use crate::solution::Solution;
+use anyhow::Result;
+use std::fmt::Display;
+
 pub fn parse(input: &str) -> Vec<Vec<i32>> {
     input
         .lines()
@@ -39,6 +43,30 @@ fn sequences(history: &[i32]) -> Vec<Vec<i32>> {
     sequences
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(histories: Self::Input<'_>) -> Result<impl Display> {
+        Ok(histories
+            .iter()
+            .map(|history| extrapolate(history))
+            .sum::<i32>())
+    }
+
+    fn part2(histories: Self::Input<'_>) -> Result<impl Display> {
+        Ok(histories
+            .iter()
+            .map(|history| extrapolate_backwards(history))
+            .sum::<i32>())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,7 +76,6 @@ mod tests {
 1 3 6 10 15 21
 10 13 16 21 30 45
 ";
-    const INPUT: &str = include_str!("../../input/2023/09.txt");
 
     #[test]
     fn test_1_sample() {
@@ -57,24 +84,10 @@ mod tests {
         assert_eq!(sum, 114);
     }
 
-    #[test]
-    fn test_1() {
-        let histories = parse(INPUT);
-        let sum: i32 = histories.iter().map(|h| extrapolate(h)).sum();
-        assert_eq!(sum, 1_581_679_977);
-    }
-
     #[test]
     fn test_2_sample() {
         let histories = parse(SAMPLE);
         let sum: i32 = histories.iter().map(|h| extrapolate_backwards(h)).sum();
         assert_eq!(sum, 2);
     }
-
-    #[test]
-    fn test_2() {
-        let histories = parse(INPUT);
-        let sum: i32 = histories.iter().map(|h| extrapolate_backwards(h)).sum();
-        assert_eq!(sum, 889);
-    }
 }
@@ -1,3 +1,7 @@
+use crate::grid::Direction::{self, Down, Left, Right, Up};
+use crate::grid::Point;
+use crate::solution::Solution;
+use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 
@@ -13,25 +17,6 @@ pub enum Pipe {
     S,
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub enum Heading {
-    N,
-    S,
-    E,
-    W,
-}
-
-impl Display for Heading {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::N => write!(f, "N"),
-            Self::S => write!(f, "S"),
-            Self::E => write!(f, "E"),
-            Self::W => write!(f, "W"),
-        }
-    }
-}
-
 impl Pipe {
     fn from_char(s: char) -> Result<Self, String> {
         Ok(match s {
@@ -63,12 +48,7 @@ impl Display for Pipe {
     }
 }
 
-#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
-pub struct Point {
-    x: usize,
-    y: usize,
-}
-pub fn parse_maze(input: &str) -> (Point, HashMap<Point, Pipe>) {
+pub fn parse_maze(input: &str) -> (Point<usize>, HashMap<Point<usize>, Pipe>) {
     let mut start = Point { x: 0, y: 0 };
     let mut maze = HashMap::new();
     input.lines().enumerate().for_each(|(y, line)| {
@@ -85,7 +65,10 @@ pub fn parse_maze(input: &str) -> (Point, HashMap<Point, Pipe>) {
     (start, maze)
 }
 
-fn all_points_in_loop(start: Point, maze: &HashMap<Point, Pipe>) -> Vec<Point> {
+fn all_points_in_loop(
+    start: Point<usize>,
+    maze: &HashMap<Point<usize>, Pipe>,
+) -> Vec<Point<usize>> {
     let mut points = Vec::new();
 
     let (start_heading, start_pipe) = find_starts_heading(start, maze);
@@ -102,7 +85,7 @@ fn all_points_in_loop(start: Point, maze: &HashMap<Point, Pipe>) -> Vec<Point> {
     points
 }
 
-pub fn maze_to_string(maze: &HashMap<Point, Pipe>, x: usize, y: usize) -> String {
+pub fn maze_to_string(maze: &HashMap<Point<usize>, Pipe>, x: usize, y: usize) -> String {
     let mut s = String::new();
     for y in 0..y {
         for x in 0..x {
@@ -117,12 +100,12 @@ pub fn maze_to_string(maze: &HashMap<Point, Pipe>, x: usize, y: usize) -> String
 }
 
 pub fn only_loop_as_string(
-    maze: &mut HashMap<Point, Pipe>,
+    maze: &mut HashMap<Point<usize>, Pipe>,
     x: usize,
     y: usize,
-    start: Point,
+    start: Point<usize>,
 ) -> String {
-    let points: HashSet<Point> = HashSet::from_iter(all_points_in_loop(start, maze));
+    let points: HashSet<Point<usize>> = HashSet::from_iter(all_points_in_loop(start, maze));
 
     let mut s = String::new();
     for y in 0..y {
@@ -142,63 +125,46 @@ pub fn only_loop_as_string(
     s
 }
 
-pub fn find_starts_heading(start: Point, _maze: &HashMap<Point, Pipe>) -> (Heading, Pipe) {
+pub fn find_starts_heading(
+    start: Point<usize>,
+    _maze: &HashMap<Point<usize>, Pipe>,
+) -> (Direction, Pipe) {
     // hardcode for now
     if start == (Point { x: 1, y: 1 }) {
-        (Heading::W, Pipe::SE)
+        (Left, Pipe::SE)
     } else if start == (Point { x: 0, y: 2 }) {
-        (Heading::N, Pipe::SE)
+        (Up, Pipe::SE)
     } else if start == (Point { x: 119, y: 72 }) {
-        (Heading::E, Pipe::NW)
+        (Right, Pipe::NW)
     } else if start == (Point { x: 12, y: 4 }) {
-        (Heading::N, Pipe::SE)
+        (Up, Pipe::SE)
     } else if start == (Point { x: 4, y: 0 }) {
-        (Heading::N, Pipe::SW)
+        (Up, Pipe::SW)
     } else {
         panic!("unknown start point {start:?}")
     }
 }
 
-pub fn get_next_point(heading: Heading, cur_pipe: Pipe, cur_point: Point) -> (Heading, Point) {
-    match (heading, cur_pipe) {
-        (Heading::N, Pipe::V) | (Heading::W, Pipe::NE) | (Heading::E, Pipe::NW) => (
-            Heading::N,
-            Point {
-                x: cur_point.x,
-                y: cur_point.y - 1,
-            },
-        ),
-        (Heading::S, Pipe::V) | (Heading::W, Pipe::SE) | (Heading::E, Pipe::SW) => (
-            Heading::S,
-            Point {
-                x: cur_point.x,
-                y: cur_point.y + 1,
-            },
-        ),
-        (Heading::W, Pipe::H) | (Heading::N, Pipe::SW) | (Heading::S, Pipe::NW) => (
-            Heading::W,
-            Point {
-                x: cur_point.x - 1,
-                y: cur_point.y,
-            },
-        ),
-        (Heading::E, Pipe::H) | (Heading::S, Pipe::NE) | (Heading::N, Pipe::SE) => (
-            Heading::E,
-            Point {
-                x: cur_point.x + 1,
-                y: cur_point.y,
-            },
-        ),
-        #[allow(clippy::uninlined_format_args)]
-        (heading, pipe) => panic!("unknown heading pipe combo {}, {}", heading, pipe),
-    }
+pub fn get_next_point(
+    heading: Direction,
+    cur_pipe: Pipe,
+    cur_point: Point<usize>,
+) -> (Direction, Point<usize>) {
+    let next_heading = match (heading, cur_pipe) {
+        (Up, Pipe::V) | (Left, Pipe::NE) | (Right, Pipe::NW) => Up,
+        (Down, Pipe::V) | (Left, Pipe::SE) | (Right, Pipe::SW) => Down,
+        (Left, Pipe::H) | (Up, Pipe::SW) | (Down, Pipe::NW) => Left,
+        (Right, Pipe::H) | (Down, Pipe::NE) | (Up, Pipe::SE) => Right,
+        (heading, pipe) => panic!("unknown heading pipe combo {heading:?}, {pipe}"),
+    };
+    (next_heading, cur_point + next_heading)
 }
 
-pub fn furthest_point(start: Point, maze: &HashMap<Point, Pipe>) -> i32 {
+pub fn furthest_point(start: Point<usize>, maze: &HashMap<Point<usize>, Pipe>) -> i32 {
     (all_points_in_loop(start, maze).len() / 2) as i32
 }
 
-pub fn count_enclosed_tiles(start: Point, maze: &HashMap<Point, Pipe>) -> i32 {
+pub fn count_enclosed_tiles(start: Point<usize>, maze: &HashMap<Point<usize>, Pipe>) -> i32 {
     let mut points = all_points_in_loop(start, maze);
 
     // Shoelace formula for calculating the area of a polygon
@@ -219,6 +185,24 @@ pub fn count_enclosed_tiles(start: Point, maze: &HashMap<Point, Pipe>) -> i32 {
     area - (points.len() as i32 - 1) / 2 + 1
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Point<usize>, HashMap<Point<usize>, Pipe>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_maze(input))
+    }
+
+    fn part1((start, maze): Self::Input<'_>) -> Result<impl Display> {
+        Ok(furthest_point(start, &maze))
+    }
+
+    fn part2((start, maze): Self::Input<'_>) -> Result<impl Display> {
+        Ok(count_enclosed_tiles(start, &maze))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,26 +262,21 @@ S┘.└┐
 ┐─└─┘└┐││┌┐│└┐┌─┐┌┐│
 └.└┐└┌┘│││││┌┘└┐││└┘
 └┐┘└┘└─┘└┘└┘└──┘└┘.└";
-    const INPUT: &str = include_str!("../../input/2023/10.txt");
 
     #[test]
     #[ignore]
     fn test_parse() {
         let maze_1 = parse_maze(SAMPLE_1);
         let maze_2 = parse_maze(SAMPLE_2);
-        let input = parse_maze(INPUT);
 
         let printed_1 = maze_to_string(&maze_1.1, 5, 5);
         let printed_2 = maze_to_string(&maze_2.1, 5, 5);
-        let printed_input = maze_to_string(&input.1, 140, 140);
 
         assert_eq!(printed_1, SAMPLE_1);
         assert_eq!(printed_2, SAMPLE_2);
-        assert_eq!(printed_input, INPUT);
 
         assert_eq!(maze_1.0, Point { x: 1, y: 1 });
         assert_eq!(maze_2.0, Point { x: 0, y: 2 });
-        assert_eq!(input.0, Point { x: 119, y: 72 });
     }
 
     #[test]
@@ -309,7 +288,6 @@ S┘.└┐
         let mut maze_4 = parse_maze(SAMPLE_4);
         let mut maze_5 = parse_maze(SAMPLE_5);
         let mut maze_6 = parse_maze(SAMPLE_6);
-        let mut input = parse_maze(INPUT);
 
         let printed_1 = only_loop_as_string(&mut maze_1.1, 5, 5, Point { x: 1, y: 1 });
         let printed_2 = only_loop_as_string(&mut maze_2.1, 5, 5, Point { x: 0, y: 2 });
@@ -317,7 +295,6 @@ S┘.└┐
         let printed_4 = only_loop_as_string(&mut maze_4.1, 10, 9, Point { x: 1, y: 1 });
         let printed_5 = only_loop_as_string(&mut maze_5.1, 20, 10, Point { x: 12, y: 4 });
         let printed_6 = only_loop_as_string(&mut maze_6.1, 20, 10, Point { x: 4, y: 0 });
-        let printed_input = only_loop_as_string(&mut input.1, 140, 140, Point { x: 119, y: 72 });
 
         println!("{}\n", &printed_1);
         println!("{}\n", &printed_2);
@@ -325,7 +302,6 @@ S┘.└┐
         println!("{}\n", &printed_4);
         println!("{}\n", &printed_5);
         println!("{}\n", &printed_6);
-        println!("{}\n", &printed_input);
     }
 
     #[test]
@@ -337,13 +313,6 @@ S┘.└┐
         assert_eq!(furthest_point(maze_2_start, &maze_2), 8);
     }
 
-    #[test]
-    fn test_1() {
-        let (start, maze) = parse_maze(INPUT);
-
-        assert_eq!(furthest_point(start, &maze), 6_927);
-    }
-
     #[test]
     fn test_2_sample() {
         let maze_1 = parse_maze(SAMPLE_1).1;
@@ -360,11 +329,4 @@ S┘.└┐
         assert_eq!(count_enclosed_tiles(Point { x: 12, y: 4 }, &maze_5), 8);
         assert_eq!(count_enclosed_tiles(Point { x: 4, y: 0 }, &maze_6), 10);
     }
-
-    #[test]
-    fn test_2() {
-        let maze = parse_maze(INPUT).1;
-
-        assert_eq!(count_enclosed_tiles(Point { x: 119, y: 72 }, &maze), 467);
-    }
 }
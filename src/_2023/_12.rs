@@ -1,9 +1,13 @@
+use crate::solution::Solution;
 use crate::_2023::_12::Spring::{Damaged, Operational, Unknown};
+use anyhow::Result;
 use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Display;
 
 pub struct Record {
     springs: Vec<Spring>,
-    contiguous_damaged: Vec<i32>,
+    contiguous_damaged: Vec<usize>,
 }
 
 impl Record {
@@ -16,7 +20,36 @@ impl Record {
         self.springs = expanded;
         self.contiguous_damaged = self.contiguous_damaged.repeat(5);
     }
-    pub fn valid_permutations(&self) -> i32 {
+    /// Counts the arrangements spring by spring, tracking how many of the groups of damaged
+    /// springs are complete and how long the group being built is, for every arrangement so far.
+    pub fn valid_permutations(&self) -> u64 {
+        let groups = &self.contiguous_damaged;
+        // (complete groups, damaged springs in the current group) -> arrangements
+        let mut counts: HashMap<(usize, usize), u64> = HashMap::from([((0, 0), 1)]);
+
+        // an extra operational spring at the end completes the last group
+        for &spring in self.springs.iter().chain([&Operational]) {
+            let mut next: HashMap<(usize, usize), u64> = HashMap::new();
+            for (&(group, run), &count) in &counts {
+                if spring != Operational && group < groups.len() && run < groups[group] {
+                    *next.entry((group, run + 1)).or_default() += count;
+                }
+                if spring != Damaged {
+                    if run == 0 {
+                        *next.entry((group, 0)).or_default() += count;
+                    } else if run == groups[group] {
+                        *next.entry((group + 1, 0)).or_default() += count;
+                    }
+                }
+            }
+            counts = next;
+        }
+
+        counts.get(&(groups.len(), 0)).copied().unwrap_or(0)
+    }
+
+    /// Tries every way of placing the missing damaged springs, too slow for expanded records.
+    pub fn valid_permutations_brute_force(&self) -> u64 {
         let unknown_indices: Vec<_> = self
             .springs
             .iter()
@@ -25,7 +58,7 @@ impl Record {
             .map(|e| e.0)
             .collect();
         let damaged_count = self.springs.iter().filter(|s| **s == Damaged).count();
-        let damaged_to_add = self.contiguous_damaged.iter().sum::<i32>() as usize - damaged_count;
+        let damaged_to_add = self.contiguous_damaged.iter().sum::<usize>() - damaged_count;
 
         unknown_indices
             .iter()
@@ -42,18 +75,18 @@ impl Record {
                 }
                 Record::valid(&springs, &self.contiguous_damaged)
             })
-            .count() as i32
+            .count() as u64
     }
 
-    pub fn valid(springs: &[Spring], contiguous_damaged: &[i32]) -> bool {
-        let found_contiguous_damaged: Vec<i32> = springs
+    pub fn valid(springs: &[Spring], contiguous_damaged: &[usize]) -> bool {
+        let found_contiguous_damaged: Vec<usize> = springs
             .split(|a| *a == Operational)
             .map(|a| {
                 a.iter()
                     .filter(|b| **b == Damaged)
                     .collect::<Vec<&Spring>>()
             })
-            .map(|a| a.len() as i32)
+            .map(|a| a.len())
             .filter(|a| *a != 0)
             .collect();
 
@@ -100,6 +133,25 @@ pub fn parse(input: &str) -> Vec<Record> {
         .collect()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(records: Self::Input<'_>) -> Result<impl Display> {
+        Ok(records.iter().map(Record::valid_permutations).sum::<u64>())
+    }
+
+    fn part2(mut records: Self::Input<'_>) -> Result<impl Display> {
+        records.iter_mut().for_each(Record::expand);
+        Ok(records.iter().map(Record::valid_permutations).sum::<u64>())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,49 +163,34 @@ mod tests {
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
 ?###???????? 3,2,1";
-    const INPUT: &str = include_str!("../../input/2023/12.txt");
 
     #[test]
     fn test_1_sample() {
         let input = parse(SAMPLE);
 
         assert_eq!(
-            input.iter().map(Record::valid_permutations).sum::<i32>(),
+            input.iter().map(Record::valid_permutations).sum::<u64>(),
             21
         );
     }
 
     #[test]
-    #[ignore]
-    fn test_1() {
-        let input = parse(INPUT);
-
-        assert_eq!(
-            input.iter().map(Record::valid_permutations).sum::<i32>(),
-            6_852
-        );
+    fn test_1_brute_force() {
+        for record in parse(SAMPLE) {
+            assert_eq!(
+                record.valid_permutations(),
+                record.valid_permutations_brute_force()
+            );
+        }
     }
 
     #[test]
-    #[ignore]
     fn test_2_sample() {
         let mut input = parse(SAMPLE);
         input.iter_mut().for_each(Record::expand);
 
         assert_eq!(
-            input.iter().map(Record::valid_permutations).sum::<i32>(),
-            525_152
-        );
-    }
-
-    #[test]
-    #[ignore]
-    fn test_2() {
-        let mut input = parse(INPUT);
-        input.iter_mut().for_each(Record::expand);
-
-        assert_eq!(
-            input.iter().map(Record::valid_permutations).sum::<i32>(),
+            input.iter().map(Record::valid_permutations).sum::<u64>(),
             525_152
         );
     }
@@ -1,4 +1,7 @@
+use crate::solution::Solution;
 use crate::_2023::_13::Reflection::Horizontal;
+use anyhow::Result;
+use std::fmt::Display;
 use std::str::FromStr;
 use Reflection::Vertical;
 
@@ -113,6 +116,24 @@ pub fn parse(input: &str) -> Vec<Valley> {
     input.split("\n\n").flat_map(str::parse).collect()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Valley>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(valleys: Self::Input<'_>) -> Result<impl Display> {
+        Ok(valleys.iter().map(Valley::score).sum::<i32>())
+    }
+
+    fn part2(mut valleys: Self::Input<'_>) -> Result<impl Display> {
+        Ok(valleys.iter_mut().map(Valley::smudged_score).sum::<i32>())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,7 +154,6 @@ mod tests {
 #####.##.
 ..##..###
 #....#..#";
-    const INPUT: &str = include_str!("../../input/2023/13.txt");
 
     #[test]
     fn test_1_sample() {
@@ -142,13 +162,6 @@ mod tests {
         assert_eq!(input.iter().map(Valley::score).sum::<i32>(), 405);
     }
 
-    #[test]
-    fn test_1() {
-        let input = parse(INPUT);
-
-        assert_eq!(input.iter().map(Valley::score).sum::<i32>(), 28_895);
-    }
-
     #[test]
     fn test_2_sample() {
         let mut input = parse(SAMPLE);
@@ -158,14 +171,4 @@ mod tests {
             400
         );
     }
-
-    #[test]
-    fn test_2() {
-        let mut input = parse(INPUT);
-
-        assert_eq!(
-            input.iter_mut().map(Valley::smudged_score).sum::<i32>(),
-            31_603
-        );
-    }
 }
@@ -1,23 +1,20 @@
+use crate::cycle;
+use crate::grid::Grid;
+use crate::solution::Solution;
+use anyhow::Result;
 use std::fmt;
 use std::fmt::Display;
 use std::ops::Range;
 use Direction::{East, North, South, West};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Dish {
-    grid: Vec<Vec<char>>,
+    grid: Grid<char>,
 }
 
 impl Display for Dish {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for (i, row) in self.grid.iter().enumerate() {
-            for &c in row {
-                write!(f, "{c}")?;
-            }
-            if i != self.grid.len() - 1 {
-                writeln!(f)?;
-            }
-        }
-        Ok(())
+        write!(f, "{}", self.grid)
     }
 }
 
@@ -61,84 +58,85 @@ fn get_windows(column: &[char]) -> Vec<Range<usize>> {
 
 impl Dish {
     pub fn load(&self) -> i32 {
-        let mut sum = 0;
-        for x in 0..self.grid[0].len() {
-            for y in 0..self.grid.len() {
-                let rock = self.grid[y][x];
-                if rock == 'O' {
-                    sum += self.grid.len() - y;
-                }
-            }
-        }
-        sum as i32
+        let height = self.grid.height() as i32;
+        self.grid
+            .iter()
+            .filter(|(_, &rock)| rock == 'O')
+            .map(|(point, _)| height - point.y)
+            .sum()
     }
+
     pub fn shift(&mut self, direction: Direction) {
         match direction {
-            North => {
-                for x in 0..self.grid[0].len() {
-                    let mut column: Vec<char> = self.grid.iter().map(|row| row[x]).rev().collect();
-
-                    let windows = get_windows(&column);
+            // columns are rolled as the rows of the transposed grid
+            North | South => {
+                let mut transposed = self.grid.transpose();
+                roll_rows(&mut transposed, matches!(direction, North));
+                self.grid = transposed.transpose();
+            }
+            East | West => roll_rows(&mut self.grid, matches!(direction, West)),
+        }
+    }
 
-                    for window in windows {
-                        column[window].sort_unstable();
-                    }
+    /// Spins the dish `times` times. The rocks settle into a loop, so only the spins up to the
+    /// second time round it are actually done.
+    pub fn spin_cycle(&mut self, times: usize) {
+        let start = self.clone();
+        *self = cycle::nth_state(
+            start,
+            |dish| {
+                let mut dish = dish.clone();
+                dish.spin();
+                dish
+            },
+            times,
+        );
+    }
 
-                    for (y, c) in column.iter().rev().enumerate() {
-                        self.grid[y][x] = *c;
-                    }
-                }
-            }
-            East => {
-                for row in &mut self.grid {
-                    let windows = get_windows(row);
+    fn spin(&mut self) {
+        self.shift(North);
+        self.shift(West);
+        self.shift(South);
+        self.shift(East);
+    }
+}
 
-                    for window in windows {
-                        row[window].sort_unstable();
-                    }
-                }
+/// Rolls the round rocks of every row towards its start, or towards its end.
+fn roll_rows(grid: &mut Grid<char>, towards_start: bool) {
+    for row in grid.rows_mut() {
+        for window in get_windows(row) {
+            if towards_start {
+                row[window].sort_unstable_by(|a, b| b.cmp(a));
+            } else {
+                row[window].sort_unstable();
             }
-            West => {
-                for row in &mut self.grid {
-                    let windows = get_windows(row);
+        }
+    }
+}
 
-                    for window in windows {
-                        row[window].sort_unstable_by(|a, b| b.cmp(a));
-                    }
-                }
-            }
-            South => {
-                for x in 0..self.grid[0].len() {
-                    let mut column: Vec<char> = self.grid.iter().map(|row| row[x]).rev().collect();
+pub fn parse(input: &str) -> Result<Dish> {
+    Ok(Dish {
+        grid: input.parse()?,
+    })
+}
 
-                    let windows = get_windows(&column);
+pub struct Day;
 
-                    for window in windows {
-                        // column[window].sort_unstable();
-                        column[window].sort_unstable_by(|a, b| b.cmp(a));
-                    }
+impl Solution for Day {
+    type Input<'a> = Dish;
 
-                    for (y, c) in column.iter().rev().enumerate() {
-                        self.grid[y][x] = *c;
-                    }
-                }
-            }
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
     }
 
-    pub fn spin_cycle(&mut self, times: i32) {
-        for _ in 0..times {
-            self.shift(North);
-            self.shift(West);
-            self.shift(South);
-            self.shift(East);
-        }
+    fn part1(mut dish: Self::Input<'_>) -> Result<impl Display> {
+        dish.shift(North);
+        Ok(dish.load())
     }
-}
 
-pub fn parse(input: &str) -> Dish {
-    Dish {
-        grid: input.lines().map(|line| line.chars().collect()).collect(),
+    fn part2(mut dish: Self::Input<'_>) -> Result<impl Display> {
+        dish.spin_cycle(1_000_000_000);
+        Ok(dish.load())
     }
 }
 
@@ -170,18 +168,16 @@ O..#.OO...
 #....###..
 #....#....";
 
-    const INPUT: &str = include_str!("../../input/2023/14.txt");
-
     #[test]
     fn test_load() {
-        let input = parse(SHIFTED_NORTH);
+        let input = parse(SHIFTED_NORTH).unwrap();
 
         assert_eq!(input.load(), 136);
     }
 
     #[test]
     fn test_cycle() {
-        let mut input = parse(SAMPLE);
+        let mut input = parse(SAMPLE).unwrap();
 
         input.spin_cycle(1);
 
@@ -237,38 +233,19 @@ O..#.OO...
 
     #[test]
     fn test_1_sample() {
-        let mut input = parse(SAMPLE);
+        let mut input = parse(SAMPLE).unwrap();
 
         input.shift(North);
 
         assert_eq!(input.load(), 136);
     }
 
-    #[test]
-    fn test_1() {
-        let mut input = parse(INPUT);
-
-        input.shift(North);
-
-        assert_eq!(input.load(), 103_614);
-    }
-
     #[test]
     fn test_2_sample() {
-        let mut input = parse(SAMPLE);
+        let mut input = parse(SAMPLE).unwrap();
 
-        input.spin_cycle(1_000);
+        input.spin_cycle(1_000_000_000);
 
         assert_eq!(input.load(), 64);
     }
-
-    #[test]
-    fn test_2() {
-        let mut input = parse(INPUT);
-
-        input.spin_cycle(1_000);
-        // input.spin_cycle(1_000_000_000);
-
-        assert_eq!(input.load(), 83_790);
-    }
 }
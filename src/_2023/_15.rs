@@ -1,3 +1,7 @@
+use crate::solution::Solution;
+use anyhow::Result;
+use std::fmt::Display;
+
 pub fn hash_seq(input: &str) -> u64 {
     input
         .trim()
@@ -52,12 +56,29 @@ pub fn focusing_power(seq: &str) -> u64 {
         })
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(sequence: Self::Input<'_>) -> Result<impl Display> {
+        Ok(hash_seq(sequence))
+    }
+
+    fn part2(sequence: Self::Input<'_>) -> Result<impl Display> {
+        Ok(focusing_power(sequence))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     const SAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
-    const INPUT: &str = include_str!("../../input/2023/15.txt");
 
     #[test]
     fn test_hash() {
@@ -70,24 +91,10 @@ mod tests {
         assert_eq!(input, 1_320);
     }
 
-    #[test]
-    fn test_1() {
-        let input = hash_seq(INPUT);
-
-        assert_eq!(input, 511_215);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = focusing_power(SAMPLE);
 
         assert_eq!(input, 145);
     }
-
-    #[test]
-    fn test_2() {
-        let input = focusing_power(INPUT);
-
-        assert_eq!(input, 236_057);
-    }
 }
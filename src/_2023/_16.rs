@@ -1,26 +1,24 @@
-use crate::_2023::_16::Heading::{Down, Right, Up};
+use crate::grid::Direction::{self, Down, Left, Right, Up};
+use crate::grid::Point;
+use crate::solution::Solution;
+use anyhow::Result;
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter, Write};
-use std::ops::Add;
-use Heading::Left;
 
 pub struct Contraption {
-    grid: HashMap<Point, char>,
+    grid: HashMap<Point<isize>, char>,
     width: usize,
     height: usize,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-pub enum Heading {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
 impl Contraption {
-    fn trace(&self, mut point: Point, mut heading: Heading, seen: &mut HashSet<(Point, Heading)>) {
+    fn trace(
+        &self,
+        mut point: Point<isize>,
+        mut heading: Direction,
+        seen: &mut HashSet<(Point<isize>, Direction)>,
+    ) {
         if seen.contains(&(point, heading)) {
             return;
         }
@@ -58,12 +56,13 @@ impl Contraption {
         }
     }
     pub fn count_energized(&self) -> usize {
-        let mut seen: HashSet<(Point, Heading)> = HashSet::new();
+        let mut seen: HashSet<(Point<isize>, Direction)> = HashSet::new();
         self.trace(Point { x: 0, y: 0 }, Right, &mut seen);
-        let set: HashSet<Point> = seen.iter().map(|(point, _)| *point).collect::<HashSet<_>>();
+        let set: HashSet<Point<isize>> =
+            seen.iter().map(|(point, _)| *point).collect::<HashSet<_>>();
         set.len()
     }
-    fn edges(&self) -> Vec<(Point, Heading)> {
+    fn edges(&self) -> Vec<(Point<isize>, Direction)> {
         let mut edges = Vec::new();
         for x in 0..self.width {
             edges.push((
@@ -104,9 +103,9 @@ impl Contraption {
         self.edges()
             .into_par_iter()
             .map(|(point, heading)| {
-                let mut seen: HashSet<(Point, Heading)> = HashSet::new();
+                let mut seen: HashSet<(Point<isize>, Direction)> = HashSet::new();
                 self.trace(point, heading, &mut seen);
-                let set: HashSet<Point> =
+                let set: HashSet<Point<isize>> =
                     seen.iter().map(|(point, _)| *point).collect::<HashSet<_>>();
                 set.len()
             })
@@ -135,37 +134,6 @@ impl Display for Contraption {
     }
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
-pub struct Point {
-    x: isize,
-    y: isize,
-}
-
-impl Add<Heading> for Point {
-    type Output = Point;
-
-    fn add(self, heading: Heading) -> Self::Output {
-        match heading {
-            Up => Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Down => Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Left => Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Right => Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
-    }
-}
-
 pub fn parse(input: &str) -> Contraption {
     let mut grid = HashMap::new();
     let mut width = 0;
@@ -190,6 +158,24 @@ pub fn parse(input: &str) -> Contraption {
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Contraption;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(contraption: Self::Input<'_>) -> Result<impl Display> {
+        Ok(contraption.count_energized())
+    }
+
+    fn part2(contraption: Self::Input<'_>) -> Result<impl Display> {
+        Ok(contraption.count_largest_energized())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,7 +190,6 @@ mod tests {
 .-.-/..|..
 .|....-|.\
 ..//.|....";
-    const INPUT: &str = include_str!("../../input/2023/16.txt");
 
     #[test]
     fn test_1_sample() {
@@ -213,24 +198,10 @@ mod tests {
         assert_eq!(input.count_energized(), 46);
     }
 
-    #[test]
-    fn test_1() {
-        let input = parse(INPUT);
-
-        assert_eq!(input.count_energized(), 7_415);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = parse(SAMPLE);
 
         assert_eq!(input.count_largest_energized(), 51);
     }
-
-    #[test]
-    fn test_2() {
-        let input = parse(INPUT);
-
-        assert_eq!(input.count_largest_energized(), 7_943);
-    }
 }
@@ -1,55 +1,20 @@
-use crate::_2023::_18::Direction::{D, L, R, U};
+use crate::grid::Direction::{self, Down, Left, Right, Up};
+use crate::grid::Point;
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::error::Error;
-use std::ops::{Add, Mul};
-use std::str::FromStr;
-
-#[derive(Copy, Clone)]
-pub enum Direction {
-    R,
-    D,
-    L,
-    U,
-}
-
-impl Mul<u32> for Direction {
-    type Output = Point;
-
-    fn mul(self, amount: u32) -> Self::Output {
-        match self {
-            U => Point {
-                x: 0,
-                y: -(amount as i32),
-            },
-            D => Point {
-                x: 0,
-                y: amount as i32,
-            },
-            L => Point {
-                x: -(amount as i32),
-                y: 0,
-            },
-            R => Point {
-                x: amount as i32,
-                y: 0,
-            },
-        }
-    }
-}
-
-impl FromStr for Direction {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "U" => U,
-            "D" => D,
-            "L" => L,
-            "R" => R,
-            _ => return Err(format!("{s} is not a valid Direction")),
-        })
-    }
+use std::fmt::Display;
+
+fn parse_direction(s: &str) -> Result<Direction, String> {
+    Ok(match s {
+        "U" => Up,
+        "D" => Down,
+        "L" => Left,
+        "R" => Right,
+        _ => return Err(format!("{s} is not a valid Direction")),
+    })
 }
 
 pub struct DigPlan {
@@ -61,7 +26,7 @@ impl DigPlan {
     fn parse(s: &str) -> Result<Self, Box<dyn Error>> {
         let mut s = s.split_whitespace();
         Ok(DigPlan {
-            direction: s.next().ok_or("no direction found")?.parse()?,
+            direction: parse_direction(s.next().ok_or("no direction found")?)?,
             amount: s.next().ok_or("no amount found")?.parse()?,
         })
     }
@@ -74,10 +39,10 @@ impl DigPlan {
         let amount = u32::from_str_radix(amount, 16)?;
         let direction = &rgb[5..6];
         let direction = match direction {
-            "0" => R,
-            "1" => D,
-            "2" => L,
-            "3" => U,
+            "0" => Right,
+            "1" => Down,
+            "2" => Left,
+            "3" => Up,
             _ => {
                 return Err(format!("{direction} is not a valid Direction").into());
             }
@@ -86,45 +51,6 @@ impl DigPlan {
     }
 }
 
-#[derive(Hash, Eq, PartialEq, Copy, Clone)]
-pub struct Point {
-    x: i32,
-    y: i32,
-}
-impl Add<Point> for Point {
-    type Output = Point;
-    fn add(self, rhs: Point) -> Self::Output {
-        Point {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-impl Add<Direction> for Point {
-    type Output = Point;
-
-    fn add(self, rhs: Direction) -> Self::Output {
-        match rhs {
-            U => Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            D => Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            L => Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-            R => Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
-    }
-}
-
 pub fn parse(input: &str) -> Vec<DigPlan> {
     input.lines().flat_map(DigPlan::parse).collect()
 }
@@ -137,7 +63,7 @@ pub fn cubic_meters_of_laval(dig_plan: &[DigPlan]) -> i64 {
     let area = dig_plan
         .iter()
         .scan(Point { x: 0, y: 0 }, |acc, plan| {
-            *acc = *acc + (plan.direction * plan.amount);
+            *acc = *acc + plan.direction * plan.amount as i32;
             Some(*acc)
         })
         .tuple_windows()
@@ -164,20 +90,33 @@ pub fn cubic_meters_of_laval(dig_plan: &[DigPlan]) -> i64 {
 // I'm not actually using this but I did for part 1 so I kept it.
 // Given the billions of nodes its not feasible for pt 2 but it may come in handy later
 pub fn flood_fill(perimeter: &mut HashSet<Point>, start: Point) {
-    // 4 directions: up, down, left, right
-    let directions = [U, D, L, R];
-
     let mut stack = vec![start];
     while let Some(point) = stack.pop() {
         if !perimeter.contains(&point) {
             perimeter.insert(point);
-            for direction in directions {
-                stack.push(point + direction);
-            }
+            stack.extend(point.neighbors());
         }
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<impl Display> {
+        Ok(cubic_meters_of_laval(&parse(input)))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<impl Display> {
+        Ok(cubic_meters_of_laval(&parse_swapped(input)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,7 +136,6 @@ R 2 (#7807d2)
 U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)";
-    const INPUT: &str = include_str!("../../input/2023/18.txt");
 
     #[test]
     fn test_1_sample() {
@@ -206,24 +144,10 @@ U 2 (#7a21e3)";
         assert_eq!(cubic_meters_of_laval(&dig_plan), 62);
     }
 
-    #[test]
-    fn test_1() {
-        let dig_plan = parse(INPUT);
-
-        assert_eq!(cubic_meters_of_laval(&dig_plan), 48_503);
-    }
-
     #[test]
     fn test_2_sample() {
         let dig_plan = parse_swapped(SAMPLE);
 
         assert_eq!(cubic_meters_of_laval(&dig_plan), 952_408_144_115);
     }
-
-    #[test]
-    fn test_2() {
-        let dig_plan = parse_swapped(INPUT);
-
-        assert_eq!(cubic_meters_of_laval(&dig_plan), 148_442_153_147_147);
-    }
 }
@@ -1,7 +1,10 @@
+use crate::solution::Solution;
 use crate::_2023::_19::Rule::{Accept, Comparison, Destination, Reject};
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::error::Error;
+use std::fmt::Display;
 use std::str::FromStr;
 
 pub struct System {
@@ -151,6 +154,20 @@ impl FromStr for System {
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = System;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse().map_err(|e| anyhow!("{e}"))
+    }
+
+    fn part1(system: Self::Input<'_>) -> Result<impl Display> {
+        Ok(system.process())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,7 +190,6 @@ hdj{m>838:A,pv}
 {x=2036,m=264,a=79,s=2244}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
-    const INPUT: &str = include_str!("../../input/2023/19.txt");
 
     #[test]
     fn test_1_sample() {
@@ -182,13 +198,6 @@ hdj{m>838:A,pv}
         assert_eq!(system.process(), 19_114);
     }
 
-    #[test]
-    fn test_1() {
-        let system: System = INPUT.parse().unwrap();
-
-        assert_eq!(system.process(), 280_909);
-    }
-
     #[test]
     #[ignore]
     fn test_2_sample() {
@@ -196,12 +205,4 @@ hdj{m>838:A,pv}
 
         assert_eq!(system.process(), 1 + 1);
     }
-
-    #[test]
-    #[ignore]
-    fn test_2() {
-        let system: System = INPUT.parse().unwrap();
-
-        assert_eq!(system.process(), 1 + 1);
-    }
 }
@@ -1,8 +1,8 @@
-use itertools::Itertools;
-use std::cell::RefCell;
+use crate::graph::Graph;
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
 use std::collections::{HashMap, VecDeque};
-use std::rc::Rc;
-use std::str::FromStr;
+use std::fmt::Display;
 use Module::{Broadcaster, Conjunction, FlipFlop};
 use Pulse::{High, Low};
 
@@ -18,168 +18,150 @@ pub enum Power {
 }
 pub enum Module {
     FlipFlop(Power),
-    Conjunction(Rc<RefCell<HashMap<String, Pulse>>>),
+    /// The last pulse from each module wired into it.
+    Conjunction(HashMap<usize, Pulse>),
     Broadcaster,
 }
 
-pub struct Machine(
-    pub HashMap<String, (Module, Vec<String>)>,
-    pub Vec<(String, Pulse, String)>,
-);
-
-impl FromStr for Machine {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map = s
-            .lines()
-            .map(|line| {
-                line.split_once(" -> ")
-                    .ok_or("unable to split line".to_string())
-            })
-            .map_ok(|(module, second)| {
-                let destinations = second
-                    .split(',')
-                    .map(str::trim)
-                    .map(str::to_string)
-                    .collect();
-                if module == "broadcaster" {
-                    Ok((module.to_string(), (Broadcaster, destinations)))
-                } else {
-                    let mut chars = module.chars();
-                    let module = match chars.next() {
-                        Some('%') => FlipFlop(Power::Off),
-                        Some('&') => Conjunction(Rc::new(RefCell::new(HashMap::new()))),
-                        _ => return Err(format!("{module} is not a valid module")),
-                    };
-                    Ok((chars.collect(), (module, destinations)))
-                }
-            })
-            .flatten()
-            .collect::<Result<HashMap<String, (Module, Vec<String>)>, _>>()?;
+pub struct Machine<'a> {
+    /// Which modules send pulses to which.
+    graph: Graph<'a>,
+    /// The module for each node of the graph, or `None` for the ones that only receive pulses.
+    modules: Vec<Option<Module>>,
+    broadcaster: usize,
+}
 
-        let conjunctions_by_key: HashMap<String, Rc<RefCell<HashMap<String, Pulse>>>> = map
-            .iter()
-            .filter_map(|it| {
-                if let Conjunction(map) = &it.1 .0 {
-                    Some((it.0.clone(), Rc::clone(map)))
-                } else {
-                    None
-                }
-            })
-            .collect();
+pub fn parse(input: &str) -> Result<Machine<'_>> {
+    let mut graph = Graph::new();
+    let mut typed = vec![];
+    for line in input.lines() {
+        let (module, destinations) = line
+            .split_once(" -> ")
+            .ok_or_else(|| anyhow!("unable to split line"))?;
+        let (name, module) = if module == "broadcaster" {
+            (module, Broadcaster)
+        } else if let Some(name) = module.strip_prefix('%') {
+            (name, FlipFlop(Power::Off))
+        } else if let Some(name) = module.strip_prefix('&') {
+            (name, Conjunction(HashMap::new()))
+        } else {
+            bail!("{module} is not a valid module");
+        };
+        typed.push((graph.intern(name), module));
+        for destination in destinations.split(',').map(str::trim) {
+            graph.add_edge(name, destination);
+        }
+    }
 
-        for (key, (_, destinations)) in &map {
-            for destination in destinations {
-                if let Some(conj) = conjunctions_by_key.get(destination) {
-                    conj.borrow_mut().insert(key.clone(), Low);
-                }
+    let mut modules: Vec<Option<Module>> = graph.nodes().map(|_| None).collect();
+    for (id, module) in typed {
+        modules[id] = Some(module);
+    }
+    for source in graph.nodes() {
+        for &destination in graph.neighbors(source) {
+            if let Some(Conjunction(inputs)) = &mut modules[destination] {
+                inputs.insert(source, Low);
             }
         }
-
-        let start_list = map
-            .get("broadcaster")
-            .expect("must have broadcaster")
-            .1
-            .iter()
-            .map(|dest| ("broadcaster".to_string(), Low, dest.clone()))
-            .collect::<Vec<(String, Pulse, String)>>();
-
-        Ok(Machine(map, start_list))
     }
+    let broadcaster = graph
+        .id("broadcaster")
+        .ok_or_else(|| anyhow!("must have broadcaster"))?;
+
+    Ok(Machine {
+        graph,
+        modules,
+        broadcaster,
+    })
 }
 
-impl Machine {
-    pub fn press_button(
-        map: &mut HashMap<String, (Module, Vec<String>)>,
-        starting_queue: &mut VecDeque<(String, Pulse, String)>,
-        times: u64,
-    ) -> u64 {
+impl Machine<'_> {
+    pub fn press_button(&mut self, times: u64) -> u64 {
         let mut low_count = 0;
         let mut high_count = 0;
 
         for _ in 0..times {
             low_count += 1; // for the button -> broadcaster
-            let mut queue = starting_queue.clone();
-            Machine::press(map, &mut queue, &mut low_count, &mut high_count);
+            self.press(&mut low_count, &mut high_count);
         }
 
         low_count * high_count
     }
-    pub fn times_for_rx(
-        map: &mut HashMap<String, (Module, Vec<String>)>,
-        starting_queue: &mut VecDeque<(String, Pulse, String)>,
-    ) -> u64 {
+    pub fn times_for_rx(&mut self) -> u64 {
         let mut count = 0;
         let mut rx_hit_once = false;
         while !rx_hit_once {
             count += 1;
-            let mut queue = starting_queue.clone();
-            rx_hit_once = Machine::press(map, &mut queue, &mut 0, &mut 0);
+            rx_hit_once = self.press(&mut 0, &mut 0);
         }
         count
     }
-    fn press(
-        map: &mut HashMap<String, (Module, Vec<String>)>,
-        queue: &mut VecDeque<(String, Pulse, String)>,
-        low_count: &mut u64,
-        high_count: &mut u64,
-    ) -> bool {
+    fn press(&mut self, low_count: &mut u64, high_count: &mut u64) -> bool {
+        let rx = self.graph.id("rx");
         let mut rx_hit_low_times = 0;
         let mut rx_hit_high_times = 0;
+        let mut queue: VecDeque<(usize, Pulse, usize)> = self
+            .graph
+            .neighbors(self.broadcaster)
+            .iter()
+            .map(|&dest| (self.broadcaster, Low, dest))
+            .collect();
         while let Some((source, signal, dest)) = queue.pop_front() {
             match signal {
                 High => *high_count += 1,
                 Low => *low_count += 1,
             };
-            match (signal, dest.as_str()) {
-                (High, "rx") => rx_hit_high_times += 1,
-                (Low, "rx") => rx_hit_low_times += 1,
-                _ => (),
+            if Some(dest) == rx {
+                match signal {
+                    High => rx_hit_high_times += 1,
+                    Low => rx_hit_low_times += 1,
+                }
             }
-            if let Some((module, destinations)) = map.get_mut(&dest) {
-                match (module, signal) {
-                    (m @ FlipFlop(Power::Off), Low) => {
-                        *m = FlipFlop(Power::On);
-                        queue.extend(
-                            destinations
-                                .iter()
-                                .map(|n| (dest.clone(), High, n.to_string())),
-                        );
-                    }
-                    (m @ FlipFlop(Power::On), Low) => {
-                        *m = FlipFlop(Power::Off);
-                        queue.extend(
-                            destinations
-                                .iter()
-                                .map(|n| (dest.clone(), Low, n.to_string())),
-                        );
-                    }
-                    (FlipFlop(_), High) => (),
-                    (Conjunction(seen), _) => {
-                        seen.borrow_mut().insert(source, signal);
-                        if seen.borrow().values().all(|pulse| *pulse == High) {
-                            queue.extend(
-                                destinations
-                                    .iter()
-                                    .map(|n| (dest.clone(), Low, n.to_string())),
-                            );
-                        } else {
-                            queue.extend(
-                                destinations
-                                    .iter()
-                                    .map(|n| (dest.clone(), High, n.to_string())),
-                            );
-                        }
+            let send = |pulse| {
+                self.graph
+                    .neighbors(dest)
+                    .iter()
+                    .map(move |&next| (dest, pulse, next))
+            };
+            match (&mut self.modules[dest], signal) {
+                (Some(m @ FlipFlop(Power::Off)), Low) => {
+                    *m = FlipFlop(Power::On);
+                    queue.extend(send(High));
+                }
+                (Some(m @ FlipFlop(Power::On)), Low) => {
+                    *m = FlipFlop(Power::Off);
+                    queue.extend(send(Low));
+                }
+                (Some(FlipFlop(_)), High) | (None, _) => (),
+                (Some(Conjunction(seen)), _) => {
+                    seen.insert(source, signal);
+                    if seen.values().all(|pulse| *pulse == High) {
+                        queue.extend(send(Low));
+                    } else {
+                        queue.extend(send(High));
                     }
-                    (Broadcaster, _) => panic!("shouldn't have visited broadcaster twice"),
                 }
+                (Some(Broadcaster), _) => panic!("shouldn't have visited broadcaster twice"),
             }
         }
         rx_hit_high_times == 0 && rx_hit_low_times == 1
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Machine<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(mut machine: Self::Input<'_>) -> Result<impl Display> {
+        Ok(machine.press_button(1_000))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,62 +180,12 @@ broadcaster -> a
 %b -> con
 &con -> output";
 
-    const INPUT: &str = include_str!("../../input/2023/20.txt");
-
     #[test]
     fn test_1_sample() {
-        let mut machine: Machine = SAMPLE.parse().unwrap();
-        let mut queue: VecDeque<(String, Pulse, String)> = machine
-            .1
-            .into_iter()
-            .map(|it| (it.0, it.1, it.2.clone()))
-            .collect::<VecDeque<_>>();
-
-        let mut machine_2: Machine = SAMPLE_2.parse().unwrap();
-        let mut queue_2: VecDeque<(String, Pulse, String)> = machine_2
-            .1
-            .into_iter()
-            .map(|it| (it.0, it.1, it.2.clone()))
-            .collect::<VecDeque<_>>();
-
-        assert_eq!(
-            Machine::press_button(&mut machine.0, &mut queue, 1_000),
-            32_000_000
-        );
-        assert_eq!(
-            Machine::press_button(&mut machine_2.0, &mut queue_2, 1_000),
-            11_687_500
-        );
-    }
-
-    #[test]
-    fn test_1() {
-        let mut machine: Machine = INPUT.parse().unwrap();
-        let mut queue: VecDeque<(String, Pulse, String)> = machine
-            .1
-            .into_iter()
-            .map(|it| (it.0, it.1, it.2.clone()))
-            .collect::<VecDeque<_>>();
-
-        assert_eq!(
-            Machine::press_button(&mut machine.0, &mut queue, 1_000),
-            777_666_211
-        );
-    }
-
-    #[test]
-    #[ignore]
-    fn test_2() {
-        let mut machine: Machine = INPUT.parse().unwrap();
-        let mut queue: VecDeque<(String, Pulse, String)> = machine
-            .1
-            .into_iter()
-            .map(|it| (it.0, it.1, it.2.clone()))
-            .collect::<VecDeque<_>>();
+        let mut machine = parse(SAMPLE).unwrap();
+        let mut machine_2 = parse(SAMPLE_2).unwrap();
 
-        assert_eq!(
-            Machine::times_for_rx(&mut machine.0, &mut queue),
-            32_000_000
-        );
+        assert_eq!(machine.press_button(1_000), 32_000_000);
+        assert_eq!(machine_2.press_button(1_000), 11_687_500);
     }
 }
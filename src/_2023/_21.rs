@@ -1,54 +1,30 @@
+use crate::grid::Point;
+use crate::solution::Solution;
 use crate::_2023::_21::Plot::{Rock, Soil};
+use anyhow::{bail, Result};
+use itertools::Itertools;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
 pub enum Plot {
     Soil,
     Rock,
 }
-#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
-pub struct Point {
-    x: i64,
-    y: i64,
-}
-
-impl Point {
-    fn neighbors(self) -> [Point; 4] {
-        [
-            Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-            Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-        ]
-    }
-}
-
 pub struct Garden {
-    grid: HashMap<Point, Plot>,
-    start: Point,
+    grid: HashMap<Point<i64>, Plot>,
+    start: Point<i64>,
     size: i64,
 }
 
 impl Garden {
     pub fn reachable_soil(&self, steps: usize) -> usize {
-        let mut next_queue: RefCell<VecDeque<Point>> = RefCell::new(VecDeque::new());
-        let mut current_queue: RefCell<VecDeque<Point>> = RefCell::new(VecDeque::new());
+        let mut next_queue: RefCell<VecDeque<Point<i64>>> = RefCell::new(VecDeque::new());
+        let mut current_queue: RefCell<VecDeque<Point<i64>>> = RefCell::new(VecDeque::new());
         next_queue.get_mut().push_front(self.start);
         for _ in 0..steps {
             (current_queue, next_queue) = (next_queue, current_queue);
-            let mut seen: HashSet<Point> = HashSet::new();
+            let mut seen: HashSet<Point<i64>> = HashSet::new();
             while let Some(point) = current_queue.get_mut().pop_front() {
                 for neighbor in point.neighbors() {
                     if let Some(Soil) = self.grid.get(&neighbor) {
@@ -64,76 +40,104 @@ impl Garden {
         next_queue.get_mut().len()
     }
 
-    pub fn reachable_soil_math(&self, steps: i64) -> i64 {
-        let even_steps = steps % 2 == 0;
-        let mut seen = HashSet::new();
-        for point in points_within_manhattan_distance(self.start, steps, self.size) {
-            if let Some(Soil) = self.grid.get(&point) {
-                let man_dist_even = manhattan_distance(self.start, point) % 2 == 0;
-                if even_steps == man_dist_even {
-                    seen.insert(point);
-                }
+    /// How many plots can be reached in exactly `steps` steps, with the garden repeating in every
+    /// direction. Walks every plot within reach, so huge step counts need
+    /// [`Garden::reachable_soil_extrapolated`].
+    pub fn reachable_soil_infinite(&self, steps: usize) -> usize {
+        reachable(&self.first_reached(steps), steps)
+    }
+
+    /// Like [`Garden::reachable_soil_infinite`], for step counts too large to walk. Once the walk
+    /// has spread over a few copies of the garden, the plots reached after `steps % size` plus a
+    /// whole number of garden sizes grow quadratically in that number. The walk carries on until
+    /// the growth settles, and the quadratic is followed from there.
+    pub fn reachable_soil_extrapolated(&self, steps: usize) -> usize {
+        let size = self.size as usize;
+        let offset = steps % size;
+        let target = (steps / size) as i64;
+        let mut gardens = SETTLED + 1;
+        loop {
+            let max_steps = offset + gardens * size;
+            if max_steps >= steps {
+                return self.reachable_soil_infinite(steps);
             }
+            let first_reached = self.first_reached(max_steps);
+            let reached: Vec<i64> = (0..=gardens)
+                .map(|n| reachable(&first_reached, offset + n * size) as i64)
+                .collect();
+            let growth: Vec<i64> = reached.windows(2).map(|w| w[1] - w[0]).collect();
+            let acceleration: Vec<i64> = growth.windows(2).map(|w| w[1] - w[0]).collect();
+
+            if acceleration[acceleration.len() - SETTLED..]
+                .iter()
+                .all_equal()
+            {
+                let remaining = target - gardens as i64;
+                return (reached[gardens]
+                    + remaining * growth[gardens - 1]
+                    + remaining * (remaining + 1) / 2 * acceleration[gardens - 2])
+                    as usize;
+            }
+            gardens *= 2;
         }
-        self.print_seen(&seen);
-        seen.len() as i64
     }
 
-    fn print_seen(&self, seen: &HashSet<Point>) {
-        for y in 0..self.size {
-            let mut row = Vec::new();
-            for x in 0..self.size {
-                let point = &Point { x, y };
-                if seen.get(point).is_some() {
-                    row.push('0');
-                } else {
-                    match self.grid.get(point).unwrap() {
-                        Soil => row.push('.'),
-                        Rock => row.push('#'),
+    /// How many plots are first reached after each number of steps up to `max_steps`, with the
+    /// garden repeating in every direction.
+    fn first_reached(&self, max_steps: usize) -> Vec<usize> {
+        let mut seen = HashSet::from([self.start]);
+        let mut frontier = vec![self.start];
+        let mut first_reached = vec![];
+        for _ in 0..=max_steps {
+            first_reached.push(frontier.len());
+            let mut next = vec![];
+            for point in frontier {
+                for neighbor in point.neighbors() {
+                    let tile = translate_to_tile(neighbor, self.size);
+                    if matches!(self.grid.get(&tile), Some(Soil)) && seen.insert(neighbor) {
+                        next.push(neighbor);
                     }
                 }
             }
-            let row: String = row.iter().collect();
-            println!("{row}");
+            frontier = next;
         }
+        first_reached
     }
 }
-fn manhattan_distance(a: Point, b: Point) -> u64 {
-    a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+
+/// How many consecutive times the growth has to speed up by the same amount to be settled.
+const SETTLED: usize = 3;
+
+/// How many plots can be reached in exactly `steps` steps, from how many are first reached after
+/// each step. A plot can be reached again every other step by stepping away and back.
+fn reachable(first_reached: &[usize], steps: usize) -> usize {
+    first_reached
+        .iter()
+        .take(steps + 1)
+        .skip(steps % 2)
+        .step_by(2)
+        .sum()
 }
-fn translate_to_tile(point: Point, grid_size: i64) -> Point {
+
+fn translate_to_tile(point: Point<i64>, grid_size: i64) -> Point<i64> {
     Point {
         x: point.x.rem_euclid(grid_size),
         y: point.y.rem_euclid(grid_size),
     }
 }
-fn points_within_manhattan_distance(
-    start: Point,
-    steps: i64,
-    grid_size: i64,
-) -> impl Iterator<Item = Point> {
-    (-steps..=steps)
-        .flat_map(move |x| {
-            (-steps..=steps).filter_map(move |y| {
-                if x.abs() + y.abs() <= steps {
-                    Some(Point {
-                        x: start.x + x,
-                        y: start.y + y,
-                    })
-                } else {
-                    None
-                }
-            })
-        })
-        .map(move |point| translate_to_tile(point, grid_size))
-}
 
-pub fn parse(input: &str) -> Garden {
+/// The garden has to be square, since part 2 tiles it in every direction.
+pub fn parse(input: &str) -> Result<Garden> {
     let mut grid = HashMap::new();
-    let mut start = Point { x: 0, y: 0 };
-    let mut size = 0;
+    let mut start = None;
+    let size = input.lines().count();
+    if size == 0 {
+        bail!("the garden is empty");
+    }
     for (y, row) in input.lines().enumerate() {
-        size = size.max(row.len());
+        if row.chars().count() != size {
+            bail!("row {y} is not {size} plots long, the garden has to be square");
+        }
         for (x, plot) in row.chars().enumerate() {
             let point = Point {
                 x: x as i64,
@@ -144,17 +148,38 @@ pub fn parse(input: &str) -> Garden {
             } else if plot == '#' {
                 grid.insert(point, Rock);
             } else if plot == 'S' {
-                start = point;
+                start = Some(point);
                 grid.insert(point, Soil);
             } else {
-                panic!("unhandled plot {plot}")
+                bail!("unhandled plot {plot}")
             }
         }
     }
-    Garden {
+    let Some(start) = start else {
+        bail!("the garden has no starting plot");
+    };
+    Ok(Garden {
         grid,
         start,
         size: size as i64,
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(garden: Self::Input<'_>) -> Result<impl Display> {
+        Ok(garden.reachable_soil(64))
+    }
+
+    fn part2(garden: Self::Input<'_>) -> Result<impl Display> {
+        Ok(garden.reachable_soil_extrapolated(26_501_365))
     }
 }
 
@@ -174,40 +199,33 @@ mod tests {
 .##.#.####.
 .##..##.##.
 ...........";
-    const INPUT: &str = include_str!("../../input/2023/21.txt");
 
     #[test]
     fn test_1_sample() {
-        let garden = parse(SAMPLE);
+        let garden = parse(SAMPLE).unwrap();
 
         assert_eq!(garden.reachable_soil(6), 16);
     }
 
     #[test]
-    fn test_1() {
-        let garden = parse(INPUT);
-
-        assert_eq!(garden.reachable_soil(64), 3_816);
-    }
-
-    #[test]
-    #[ignore]
     fn test_2_sample() {
-        let garden = parse(SAMPLE);
-
-        assert_eq!(garden.reachable_soil_math(6), 16);
-        assert_eq!(garden.reachable_soil_math(10), 50);
-        assert_eq!(garden.reachable_soil_math(100), 6_536);
-        assert_eq!(garden.reachable_soil_math(500), 167_004);
-        assert_eq!(garden.reachable_soil_math(1_000), 668_697);
-        assert_eq!(garden.reachable_soil_math(5_000), 16_733_044);
+        let garden = parse(SAMPLE).unwrap();
+
+        assert_eq!(garden.reachable_soil_infinite(6), 16);
+        assert_eq!(garden.reachable_soil_infinite(10), 50);
+        assert_eq!(garden.reachable_soil_infinite(50), 1_594);
+        assert_eq!(garden.reachable_soil_infinite(100), 6_536);
+        assert_eq!(garden.reachable_soil_infinite(500), 167_004);
+        assert_eq!(garden.reachable_soil_extrapolated(500), 167_004);
+        assert_eq!(garden.reachable_soil_extrapolated(1_000), 668_697);
+        assert_eq!(garden.reachable_soil_extrapolated(5_000), 16_733_044);
     }
 
     #[test]
-    #[ignore]
-    fn test_2() {
-        let garden = parse(INPUT);
-
-        assert_eq!(garden.reachable_soil_math(26_501_365), 3_816);
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("..S\n...").is_err());
+        assert!(parse(".S\n.x").is_err());
+        assert!(parse("..\n..").is_err());
     }
 }
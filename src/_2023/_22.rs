@@ -1,5 +1,8 @@
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 use std::hash::Hash;
 
 pub struct Space {
@@ -183,6 +186,26 @@ pub fn parse(input: &str) -> Space {
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Space;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut space = parse(input);
+        space.settle();
+        Ok(space)
+    }
+
+    fn part1(space: Self::Input<'_>) -> Result<impl Display> {
+        Ok(space.disintegrateable_bricks())
+    }
+
+    fn part2(space: Self::Input<'_>) -> Result<impl Display> {
+        Ok(space.chain_reaction_bricks())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,7 +218,6 @@ mod tests {
 2,0,5~2,2,5
 0,1,6~2,1,6
 1,1,8~1,1,9";
-    const INPUT: &str = include_str!("../../input/2023/22.txt");
 
     #[test]
     fn test_1_sample() {
@@ -205,14 +227,6 @@ mod tests {
         assert_eq!(space.disintegrateable_bricks(), 5);
     }
 
-    #[test]
-    fn test_1() {
-        let mut space = parse(INPUT);
-        space.settle();
-
-        assert_eq!(space.disintegrateable_bricks(), 461);
-    }
-
     #[test]
     fn test_2_sample() {
         let mut space = parse(SAMPLE);
@@ -220,13 +234,4 @@ mod tests {
 
         assert_eq!(space.chain_reaction_bricks(), 7);
     }
-
-    #[test]
-    #[ignore]
-    fn test_2() {
-        let mut space = parse(INPUT);
-        space.settle();
-
-        assert_eq!(space.chain_reaction_bricks(), 74_074);
-    }
 }
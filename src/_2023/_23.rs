@@ -1,173 +1,194 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use crate::grid::{Direction, Grid, Point};
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt::Display;
 use std::str::FromStr;
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
-pub struct Point {
-    x: i32,
-    y: i32,
+pub struct SnowIsland {
+    grid: Grid<char>,
+    start: Point,
+    goal: Point,
 }
 
-impl Point {
-    fn neighbors(self) -> [Point; 4] {
-        [
-            Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-            Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-        ]
+impl FromStr for SnowIsland {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.parse()?;
+        let start = grid
+            .find(&'.')
+            .ok_or_else(|| anyhow!("the island has no paths"))?;
+        let goal = Point {
+            x: grid.width() as i32 - 2,
+            y: grid.height() as i32 - 1,
+        };
+
+        Ok(SnowIsland { grid, start, goal })
     }
 }
 
-pub struct SnowIsland {
-    grid: HashMap<Point, char>,
-    height: i32,
-    width: i32,
+/// The island with its long corridors contracted: the nodes are the start, the goal and every
+/// point where paths meet, and the edges are the corridors between them, weighted by length.
+struct Trails {
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    goal: usize,
 }
 
-impl FromStr for SnowIsland {
-    type Err = String;
+// below this many steps into the search, branches are explored in parallel
+const PARALLEL_DEPTH: u32 = 6;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut height = 0;
-        let mut width = 0;
-        let grid = s
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                height = height.max(y as i32 + 1);
-                width = width.max(line.len() as i32);
-                line.chars().enumerate().map(move |(x, c)| {
-                    (
-                        Point {
-                            x: x as i32,
-                            y: y as i32,
-                        },
-                        c,
-                    )
-                })
-            })
-            .collect::<HashMap<_, _>>();
+impl Trails {
+    fn longest_path(&self) -> Result<usize> {
+        if self.edges.len() > 64 {
+            bail!(
+                "the island has {} junctions, only 64 can be tracked",
+                self.edges.len()
+            );
+        }
+        // the goal is a dead end, so the only junction leading to it has to go there
+        let last = (0..self.edges.len())
+            .filter(|&node| self.edges[node].iter().any(|&(next, _)| next == self.goal))
+            .collect::<Vec<_>>();
+        let last = if let [last] = last[..] {
+            Some(last)
+        } else {
+            None
+        };
 
-        Ok(SnowIsland {
-            grid,
-            height,
-            width,
-        })
+        self.longest_from(self.start, 1 << self.start, last, 0)
+            .ok_or_else(|| anyhow!("the goal can't be reached"))
+    }
+
+    fn longest_from(
+        &self,
+        node: usize,
+        visited: u64,
+        last: Option<usize>,
+        depth: u32,
+    ) -> Option<usize> {
+        if node == self.goal {
+            return Some(0);
+        }
+        let edges = &self.edges[node];
+        if Some(node) == last {
+            return edges
+                .iter()
+                .find(|&&(next, _)| next == self.goal)
+                .map(|&(_, steps)| steps);
+        }
+
+        let walk = |&(next, steps): &(usize, usize)| {
+            if visited & (1 << next) != 0 {
+                return None;
+            }
+            self.longest_from(next, visited | (1 << next), last, depth + 1)
+                .map(|rest| rest + steps)
+        };
+        if depth < PARALLEL_DEPTH {
+            edges.par_iter().filter_map(walk).max()
+        } else {
+            edges.iter().filter_map(walk).max()
+        }
     }
 }
 
 impl SnowIsland {
-    pub fn longest_climbing_path(&self) -> usize {
-        0
+    /// Part 2, where slopes can be walked like any other path.
+    pub fn longest_climbing_path(&self) -> Result<usize> {
+        self.trails(false)?.longest_path()
     }
-    pub fn longest_path(&self) -> usize {
-        let start = Point { x: 1, y: 0 };
-        let goal = Point {
-            x: self.width - 2,
-            y: self.height - 1,
-        };
 
-        let path = self.dfs(start, goal).into_iter().collect::<HashSet<_>>();
-
-        // for y in 0..self.height {
-        //     for x in 0..self.width {
-        //         let point = &Point { x, y };
-        //         if path.contains(point) {
-        //             print!("O");
-        //         } else {
-        //             print!("{}", self.grid.get(point).unwrap());
-        //         }
-        //     }
-        //     println!();
-        // }
-
-        // start doesn't count as taking a step
-        path.len() - 1
+    pub fn longest_path(&self) -> Result<usize> {
+        self.trails(true)?.longest_path()
     }
-    fn dfs(&self, start: Point, goal: Point) -> Vec<Point> {
-        let mut path_stack = VecDeque::new();
-        let mut longest_path = Vec::new();
 
-        path_stack.push_front((vec![start], HashSet::new()));
-
-        while let Some((mut path, mut visited)) = path_stack.pop_front() {
-            let current = *path.last().expect("no path should be empty");
-
-            if current == goal {
-                if path.len() > longest_path.len() {
-                    longest_path = path;
+    fn trails(&self, slippery: bool) -> Result<Trails> {
+        let is_junction = |point: Point| {
+            point == self.start
+                || point == self.goal
+                || point
+                    .neighbors()
+                    .into_iter()
+                    .filter(|&n| self.grid.get(n).is_some_and(|&c| c != '#'))
+                    .count()
+                    > 2
+        };
+        let junctions: HashMap<Point, usize> = self
+            .grid
+            .iter()
+            .filter(|&(point, &c)| c != '#' && is_junction(point))
+            .enumerate()
+            .map(|(id, (point, _))| (point, id))
+            .collect();
+
+        let mut edges = vec![vec![]; junctions.len()];
+        for (&junction, &id) in &junctions {
+            // follow each corridor until it reaches the next junction
+            for first in self.valid_neighbors(junction, slippery) {
+                let (mut previous, mut current, mut steps) = (junction, first, 1);
+                while !junctions.contains_key(&current) {
+                    let Some(next) = self
+                        .valid_neighbors(current, slippery)
+                        .into_iter()
+                        .find(|&next| next != previous)
+                    else {
+                        break;
+                    };
+                    (previous, current, steps) = (current, next, steps + 1);
+                }
+                if let Some(&next) = junctions.get(&current) {
+                    edges[id].push((next, steps));
                 }
-                continue;
-            }
-
-            visited.insert(current);
-
-            let mut valid_neighbors = self
-                .valid_neighbors(current)
-                .into_iter()
-                .filter(|neighbor| !visited.contains(neighbor));
-
-            // Optimization - only clone path + visited if there is a branch in the path
-            // if there is only one option, mutate in place
-            // For pt1, there is always at least 1 neighbor because the graph is directed
-            // For Pt 2, this check is necessary because the lack of slopes introduce cycles
-            let Some(first_neighbor) = valid_neighbors.next() else {
-                continue;
-            };
-
-            // iterator is empty if there is only one neighbor, which is most of the time
-            for neighbor in valid_neighbors {
-                let mut new_path = path.clone();
-                new_path.push(neighbor);
-                path_stack.push_front((new_path, visited.clone()));
             }
-            path.push(first_neighbor);
-            path_stack.push_front((path, visited));
         }
-        longest_path
+
+        let goal = *junctions
+            .get(&self.goal)
+            .ok_or_else(|| anyhow!("the goal at {:?} is not a path", self.goal))?;
+        Ok(Trails {
+            edges,
+            start: junctions[&self.start],
+            goal,
+        })
     }
-    fn valid_neighbors(&self, point: Point) -> Vec<Point> {
-        let mut neighbors = Vec::new();
-        for neighbor in point.neighbors() {
-            match self.grid.get(&neighbor) {
-                Some('.') => neighbors.push(neighbor),
-                Some('>') => {
-                    if neighbor.x == point.x + 1 {
-                        neighbors.push(neighbor);
-                    }
-                }
-                Some('<') => {
-                    if neighbor.x == point.x - 1 {
-                        neighbors.push(neighbor);
-                    }
-                }
-                Some('v') => {
-                    if neighbor.y == point.y + 1 {
-                        neighbors.push(neighbor);
-                    }
-                }
-                Some('^') => {
-                    if neighbor.y == point.y - 1 {
-                        neighbors.push(neighbor);
+
+    fn valid_neighbors(&self, point: Point, slippery: bool) -> Vec<Point> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(|direction| {
+                let neighbor = point + direction;
+                match self.grid.get(neighbor) {
+                    None | Some('#') => None,
+                    Some('.') => Some(neighbor),
+                    // slopes can only be walked down
+                    Some(&slope) if slippery && Direction::from_arrow(slope) != Some(direction) => {
+                        None
                     }
+                    Some(_) => Some(neighbor),
                 }
-                Some('#' | _) | None => (),
-            }
-        }
+            })
+            .collect()
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = SnowIsland;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse()
+    }
+
+    fn part1(island: Self::Input<'_>) -> Result<impl Display> {
+        island.longest_path()
+    }
 
-        neighbors
+    fn part2(island: Self::Input<'_>) -> Result<impl Display> {
+        island.longest_climbing_path()
     }
 }
 
@@ -199,35 +220,27 @@ mod tests {
 #.###.###.#.###.#.#v###
 #.....###...###...#...#
 #####################.#";
-    const INPUT: &str = include_str!("../../input/2023/23.txt");
 
     #[test]
     fn test_1_sample() {
         let island: SnowIsland = SAMPLE.parse().unwrap();
 
-        assert_eq!(island.longest_path(), 94);
-    }
-
-    #[test]
-    fn test_1() {
-        let island: SnowIsland = INPUT.parse().unwrap();
-
-        assert_eq!(island.longest_path(), 2_334);
+        assert_eq!(island.longest_path().unwrap(), 94);
     }
 
     #[test]
-    #[ignore]
     fn test_2_sample() {
         let island: SnowIsland = SAMPLE.parse().unwrap();
 
-        assert_eq!(island.longest_path(), 154);
+        assert_eq!(island.longest_climbing_path().unwrap(), 154);
     }
 
     #[test]
-    #[ignore]
-    fn test_2() {
-        let island: SnowIsland = INPUT.parse().unwrap();
+    fn test_unreachable_goal() {
+        let walled_in: SnowIsland = "#.###\n#.#.#\n###.#".parse().unwrap();
+        let no_goal: SnowIsland = "#.#\n#.#\n###".parse().unwrap();
 
-        assert_eq!(island.longest_path(), 1 + 1);
+        assert!(walled_in.longest_path().is_err());
+        assert!(no_goal.longest_climbing_path().is_err());
     }
 }
@@ -1,4 +1,8 @@
+use crate::math;
+use crate::solution::Solution;
+use anyhow::{bail, Context, Result};
 use itertools::Itertools;
+use std::fmt::Display;
 use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Debug)]
@@ -43,9 +47,17 @@ impl Hailstone {
         Some((px, py))
     }
     pub fn intersects(&self, other: &Hailstone, area: (i128, i128)) -> bool {
-        let Some(intersection) = self.intersection(other) else {
-            return false;
-        };
+        self.intersection_in_area(other, area).is_some()
+    }
+
+    /// Where the paths of both hailstones cross inside of the square `area`, if they cross there
+    /// in the future of both.
+    pub fn intersection_in_area(
+        &self,
+        other: &Hailstone,
+        area: (i128, i128),
+    ) -> Option<(f64, f64)> {
+        let intersection = self.intersection(other)?;
         #[allow(clippy::cast_precision_loss)]
         let area = (area.0 as f64, area.1 as f64);
         let intersects_in_area = intersection.0 >= area.0
@@ -57,7 +69,16 @@ impl Hailstone {
         let self_intersects_future = (intersection.0 - self.px as f64) / self.vx as f64 >= 0f64;
         #[allow(clippy::cast_precision_loss)]
         let other_intersects_future = (intersection.0 - other.px as f64) / other.vx as f64 >= 0f64;
-        intersects_in_area && self_intersects_future && other_intersects_future
+        (intersects_in_area && self_intersects_future && other_intersects_future)
+            .then_some(intersection)
+    }
+
+    fn position(&self) -> [i128; 3] {
+        [self.px, self.py, self.pz]
+    }
+
+    fn velocity(&self) -> [i128; 3] {
+        [self.vx, self.vy, self.vz]
     }
 }
 
@@ -111,14 +132,89 @@ pub fn intersections_in_area(hailstones: &[Hailstone], area: (i128, i128)) -> us
         .count()
 }
 
-pub fn magic_rock(_hailstones: &[Hailstone]) -> Hailstone {
-    Hailstone {
-        px: 0,
-        py: 0,
-        pz: 0,
-        vx: 0,
-        vy: 0,
-        vz: 0,
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn minus(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// Finds the rock that hits every hailstone, from the first three.
+///
+/// The rock hits hailstone `i` when `(P - p_i) x (V - v_i) = 0`. Seen from the first hailstone
+/// (subtracting its position and velocity from everything), the rock passes through the origin,
+/// so `P x V = 0` and what is left for the other two hailstones is linear:
+/// `P x v_i + p_i x V = p_i x v_i`. Those are 6 equations for the 6 unknowns.
+///
+/// # Errors
+///
+/// * if there are fewer than 3 hailstones
+/// * if the hailstones don't pin down a single rock, e.g. because their paths are parallel
+/// * if no rock hits all three
+pub fn magic_rock(hailstones: &[Hailstone]) -> Result<Hailstone> {
+    let [first, second, third, ..] = hailstones else {
+        bail!(
+            "a rock needs 3 hailstones to aim at, got {}",
+            hailstones.len()
+        );
+    };
+
+    let mut matrix = vec![];
+    let mut rhs = vec![];
+    for hailstone in [second, third] {
+        let p = minus(hailstone.position(), first.position());
+        let v = minus(hailstone.velocity(), first.velocity());
+        // P x v and p x V, one row per axis
+        matrix.push(vec![0, v[2], -v[1], 0, -p[2], p[1]]);
+        matrix.push(vec![-v[2], 0, v[0], p[2], 0, -p[0]]);
+        matrix.push(vec![v[1], -v[0], 0, -p[1], p[0], 0]);
+        rhs.extend(cross(p, v));
+    }
+
+    let solution = math::solve_exact(&matrix, &rhs)
+        .context("the hailstones don't line up for a single rock")?;
+    let [px, py, pz, vx, vy, vz] = solution[..] else {
+        unreachable!("6 unknowns have 6 values");
+    };
+    let [px, py, pz, vx, vy, vz] = [px, py, pz, vx, vy, vz].map(i128::from);
+    if cross([px, py, pz], [vx, vy, vz]) != [0, 0, 0] {
+        bail!("the rock misses the first hailstone");
+    }
+
+    Ok(Hailstone {
+        px: px + first.px,
+        py: py + first.py,
+        pz: pz + first.pz,
+        vx: vx + first.vx,
+        vy: vy + first.vy,
+        vz: vz + first.vz,
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input).map_err(anyhow::Error::msg)
+    }
+
+    fn part1(hailstones: Self::Input<'_>) -> Result<impl Display> {
+        Ok(intersections_in_area(
+            &hailstones,
+            (200_000_000_000_000, 400_000_000_000_000),
+        ))
+    }
+
+    fn part2(hailstones: Self::Input<'_>) -> Result<impl Display> {
+        let rock = magic_rock(&hailstones)?;
+        Ok(rock.px + rock.py + rock.pz)
     }
 }
 
@@ -132,7 +228,6 @@ mod tests {
 20, 25, 34 @ -2, -2, -4
 12, 31, 28 @ -1, -2, -1
 20, 19, 15 @  1, -5, -3";
-    const INPUT: &str = include_str!("../../input/2023/24.txt");
 
     #[test]
     fn test_1_sample() {
@@ -142,22 +237,35 @@ mod tests {
     }
 
     #[test]
-    fn test_1() {
-        let hailstones = parse(INPUT).unwrap();
+    fn test_1_intersection_points() {
+        let hailstones = parse(SAMPLE).unwrap();
+        let crossing = |a: usize, b: usize| {
+            hailstones[a]
+                .intersection_in_area(&hailstones[b], (7, 27))
+                .map(|(x, y)| {
+                    (
+                        (x * 1_000.0).round() / 1_000.0,
+                        (y * 1_000.0).round() / 1_000.0,
+                    )
+                })
+        };
 
-        assert_eq!(
-            intersections_in_area(&hailstones, (200_000_000_000_000, 400_000_000_000_000)),
-            16_018
-        );
+        assert_eq!(crossing(0, 1), Some((14.333, 15.333)));
+        assert_eq!(crossing(0, 2), Some((11.667, 16.667)));
+        // outside of the area
+        assert_eq!(crossing(0, 3), None);
+        // in the past of the first hailstone
+        assert_eq!(crossing(0, 4), None);
+        // parallel, so they never meet
+        assert_eq!(crossing(1, 2), None);
     }
 
     #[test]
-    #[ignore]
     fn test_2_sample() {
         let hailstones = parse(SAMPLE).unwrap();
 
         assert_eq!(
-            magic_rock(&hailstones),
+            magic_rock(&hailstones).unwrap(),
             Hailstone {
                 px: 24,
                 py: 13,
@@ -170,20 +278,16 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
-    fn test_2() {
-        let hailstones = parse(INPUT).unwrap();
+    fn test_2_degenerate() {
+        let parallel = parse(
+            "\
+0, 0, 0 @ 1, 1, 1
+1, 0, 0 @ 1, 1, 1
+2, 0, 0 @ 1, 1, 1",
+        )
+        .unwrap();
 
-        assert_eq!(
-            magic_rock(&hailstones),
-            Hailstone {
-                px: 24,
-                py: 13,
-                pz: 10,
-                vx: -3,
-                vy: 1,
-                vz: 2
-            }
-        );
+        assert!(magic_rock(&parallel).is_err());
+        assert!(magic_rock(&parallel[..2]).is_err());
     }
 }
@@ -23,3 +23,33 @@ pub mod _22;
 pub mod _23;
 pub mod _24;
 pub mod _25;
+
+use crate::registry::Entry;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new::<_01::Day>(2023, 1),
+    Entry::new::<_02::Day>(2023, 2),
+    Entry::new::<_03::Day>(2023, 3),
+    Entry::new::<_04::Day>(2023, 4),
+    Entry::new::<_05::Day>(2023, 5),
+    Entry::new::<_06::Day>(2023, 6),
+    Entry::new::<_07::Day>(2023, 7),
+    Entry::new::<_08::Day>(2023, 8),
+    Entry::new::<_09::Day>(2023, 9),
+    Entry::new::<_10::Day>(2023, 10),
+    Entry::new::<_11::Day>(2023, 11),
+    Entry::new::<_12::Day>(2023, 12),
+    Entry::new::<_13::Day>(2023, 13),
+    Entry::new::<_14::Day>(2023, 14),
+    Entry::new::<_15::Day>(2023, 15),
+    Entry::new::<_16::Day>(2023, 16),
+    Entry::new::<_17::Day>(2023, 17),
+    Entry::new::<_18::Day>(2023, 18),
+    Entry::new::<_19::Day>(2023, 19),
+    Entry::new::<_20::Day>(2023, 20),
+    Entry::new::<_21::Day>(2023, 21),
+    Entry::new::<_22::Day>(2023, 22),
+    Entry::new::<_23::Day>(2023, 23),
+    Entry::new::<_24::Day>(2023, 24),
+    Entry::new::<_25::Day>(2023, 25),
+];
@@ -1,5 +1,7 @@
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
+use std::fmt::Display;
 /// Parses the input string, which is expected to contain lines of two integers
 /// separated by whitespace. Each integer on a line represents a number in one
 /// of two columns. The function returns a result containing a tuple of vectors
@@ -139,6 +141,24 @@ pub fn similarity_score((left, right): &(Vec<i32>, Vec<i32>)) -> i32 {
     })
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(lists: Self::Input<'_>) -> Result<impl Display> {
+        Ok(sum_of_distances(&lists))
+    }
+
+    fn part2(lists: Self::Input<'_>) -> Result<impl Display> {
+        Ok(similarity_score(&lists))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,7 +171,6 @@ mod tests {
 3   9
 3   3
 ";
-    const INPUT: &str = include_str!("../../input/2024/01.txt");
 
     #[test]
     fn test_1_sample() {
@@ -161,14 +180,6 @@ mod tests {
         assert_eq!(sum, 11);
     }
 
-    #[test]
-    fn test_1() {
-        let lists = parse(INPUT).unwrap();
-        let sum = sum_of_distances(&lists);
-
-        assert_eq!(sum, 2_113_135);
-    }
-
     #[test]
     fn test_2_sample() {
         let lists = parse(SAMPLE).unwrap();
@@ -176,12 +187,4 @@ mod tests {
 
         assert_eq!(score, 31);
     }
-
-    #[test]
-    fn test_2() {
-        let lists = parse(INPUT).unwrap();
-        let score = similarity_score(&lists);
-
-        assert_eq!(score, 19_097_157);
-    }
 }
@@ -1,5 +1,7 @@
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
+use std::fmt::Display;
 
 #[derive(Debug)]
 pub struct Report(Vec<i32>);
@@ -67,6 +69,24 @@ pub fn parse(input: &str) -> Result<Vec<Report>> {
         .collect()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(reports: Self::Input<'_>) -> Result<impl Display> {
+        Ok(reports.iter().filter(|r| r.is_safe()).count())
+    }
+
+    fn part2(reports: Self::Input<'_>) -> Result<impl Display> {
+        Ok(reports.iter().filter(|r| r.is_safe_permute()).count())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,7 +99,6 @@ mod tests {
 8 6 4 4 1
 1 3 6 7 9
 ";
-    const INPUT: &str = include_str!("../../input/2024/02.txt");
 
     #[test]
     fn test_1_sample() {
@@ -89,14 +108,6 @@ mod tests {
         assert_eq!(number_safe, 2);
     }
 
-    #[test]
-    fn test_1() {
-        let input = parse(INPUT).unwrap();
-        let number_safe = input.iter().filter(|r| r.is_safe()).count();
-
-        assert_eq!(number_safe, 585);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = parse(SAMPLE).unwrap();
@@ -104,12 +115,4 @@ mod tests {
 
         assert_eq!(number_safe, 4);
     }
-
-    #[test]
-    fn test_2() {
-        let input = parse(INPUT).unwrap();
-        let number_safe = input.iter().filter(|r| r.is_safe_permute()).count();
-
-        assert_eq!(number_safe, 626);
-    }
 }
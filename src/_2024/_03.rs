@@ -1,5 +1,7 @@
+use crate::solution::Solution;
 use anyhow::Result;
 use regex::Regex;
+use std::fmt::Display;
 
 #[derive(Debug)]
 pub enum Instruction {
@@ -61,6 +63,24 @@ pub fn uncorrupt_instructions_with_conditionals(instructions: &[Instruction]) ->
         .sum()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(instructions: Self::Input<'_>) -> Result<impl Display> {
+        Ok(uncorrupt_instructions(&instructions))
+    }
+
+    fn part2(instructions: Self::Input<'_>) -> Result<impl Display> {
+        Ok(uncorrupt_instructions_with_conditionals(&instructions))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,7 +88,6 @@ mod tests {
     const SAMPLE: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
     const SAMPLE_2: &str =
         "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-    const INPUT: &str = include_str!("../../input/2024/03.txt");
 
     #[test]
     fn test_1_sample() {
@@ -78,14 +97,6 @@ mod tests {
         assert_eq!(sum, 161);
     }
 
-    #[test]
-    fn test_1() {
-        let instructions = parse(INPUT).unwrap();
-        let sum = uncorrupt_instructions(&instructions);
-
-        assert_eq!(sum, 170_778_545);
-    }
-
     #[test]
     fn test_2_sample() {
         let instructions = parse(SAMPLE_2).unwrap();
@@ -93,12 +104,4 @@ mod tests {
 
         assert_eq!(sum, 48);
     }
-
-    #[test]
-    fn test_2() {
-        let instructions = parse(INPUT).unwrap();
-        let sum = uncorrupt_instructions_with_conditionals(&instructions);
-
-        assert_eq!(sum, 82_868_252);
-    }
 }
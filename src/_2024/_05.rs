@@ -1,7 +1,9 @@
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::fmt::Display;
 use std::num::ParseIntError;
 
 #[derive(Debug, PartialEq, Eq, Hash, Ord, PartialOrd, Clone, Copy)]
@@ -90,6 +92,24 @@ pub fn p2(ordering_rules: &HashSet<OrderingRule>, pages: &mut [Page]) -> u32 {
         .sum()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (HashSet<OrderingRule>, Vec<Page>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((ordering_rules, pages): Self::Input<'_>) -> Result<impl Display> {
+        Ok(p1(&ordering_rules, &pages))
+    }
+
+    fn part2((ordering_rules, mut pages): Self::Input<'_>) -> Result<impl Display> {
+        Ok(p2(&ordering_rules, &mut pages))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,7 +143,6 @@ mod tests {
 75,97,47,61,53
 61,13,29
 97,13,75,29,47";
-    const INPUT: &str = include_str!("../../input/2024/05.txt");
 
     #[test]
     fn test_1_sample() {
@@ -133,14 +152,6 @@ mod tests {
         assert_eq!(sum, 143);
     }
 
-    #[test]
-    fn test_1() {
-        let (ordering_rules, pages) = parse(INPUT).unwrap();
-        let sum = p1(&ordering_rules, &pages);
-
-        assert_eq!(sum, 6_041);
-    }
-
     #[test]
     fn test_2_sample() {
         let (ordering_rules, mut pages) = parse(SAMPLE).unwrap();
@@ -148,12 +159,4 @@ mod tests {
 
         assert_eq!(sum, 123);
     }
-
-    #[test]
-    fn test_2() {
-        let (ordering_rules, mut pages) = parse(INPUT).unwrap();
-        let sum = p2(&ordering_rules, &mut pages);
-
-        assert_eq!(sum, 4_884);
-    }
 }
@@ -1,4 +1,6 @@
-use anyhow::Result;
+use crate::grid::{Direction, Point};
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use rayon::iter::ParallelIterator;
 use rayon::prelude::IntoParallelIterator;
 use std::collections::{HashMap, HashSet};
@@ -29,20 +31,6 @@ pub struct Grid {
     start: (Point, Direction),
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub struct Point {
-    x: i32,
-    y: i32,
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
 pub fn parse(input: &str) -> Result<Grid> {
     let mut map = HashMap::new();
     let mut start = (Point { x: 0, y: 0 }, Direction::Up);
@@ -54,12 +42,8 @@ pub fn parse(input: &str) -> Result<Grid> {
                 x: x as i32,
                 y: y as i32,
             };
-            match ch {
-                '^' => start = (point, Direction::Up),
-                '>' => start = (point, Direction::Right),
-                '<' => start = (point, Direction::Left),
-                'v' => start = (point, Direction::Down),
-                _ => (),
+            if let Some(direction) = Direction::from_arrow(ch) {
+                start = (point, direction);
             }
             map.insert(point, ch);
             max_x = max_x.max(x as i32);
@@ -85,12 +69,7 @@ pub fn cycles(grid: &mut Grid) -> usize {
                 .into_par_iter()
                 .filter_map(|x| {
                     // Define the adjacent points around (x, y)
-                    let adjacent_points = [
-                        Point { x: x - 1, y },
-                        Point { x: x + 1, y },
-                        Point { x, y: y - 1 },
-                        Point { x, y: y + 1 },
-                    ];
+                    let adjacent_points = Point { x, y }.neighbors();
 
                     // at least one adjacent point must be on the walked path
                     if adjacent_points.iter().any(|&adj| {
@@ -142,33 +121,11 @@ pub fn travel_and_count(grid: &mut Grid) -> Option<usize> {
             '|' | '-' => grid.map.insert(current, '+'),
             _ => None,
         };
-        let next = match direction {
-            Direction::Up => Point {
-                x: current.x,
-                y: current.y - 1,
-            },
-            Direction::Down => Point {
-                x: current.x,
-                y: current.y + 1,
-            },
-            Direction::Left => Point {
-                x: current.x - 1,
-                y: current.y,
-            },
-            Direction::Right => Point {
-                x: current.x + 1,
-                y: current.y,
-            },
-        };
+        let next = current + direction;
 
         match grid.map.get(&next) {
             Some('#') => {
-                direction = match direction {
-                    Direction::Up => Direction::Right,
-                    Direction::Right => Direction::Down,
-                    Direction::Down => Direction::Left,
-                    Direction::Left => Direction::Up,
-                };
+                direction = direction.turn_right();
             }
             _ => {
                 current = next;
@@ -179,6 +136,24 @@ pub fn travel_and_count(grid: &mut Grid) -> Option<usize> {
     Some(travelled_positions.len())
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(mut grid: Self::Input<'_>) -> Result<impl Display> {
+        travel_and_count(&mut grid).ok_or_else(|| anyhow!("the guard never leaves the grid"))
+    }
+
+    fn part2(mut grid: Self::Input<'_>) -> Result<impl Display> {
+        Ok(cycles(&mut grid))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,7 +170,6 @@ mod tests {
 #.........
 ......#...
 ";
-    const INPUT: &str = include_str!("../../input/2024/06.txt");
 
     #[test]
     fn test_1_sample() {
@@ -205,14 +179,6 @@ mod tests {
         assert_eq!(steps, 41);
     }
 
-    #[test]
-    fn test_1() {
-        let mut grid = parse(INPUT).unwrap();
-        let steps = travel_and_count(&mut grid).unwrap();
-
-        assert_eq!(steps, 5_086);
-    }
-
     #[test]
     fn test_2_sample() {
         let mut grid = parse(SAMPLE).unwrap();
@@ -220,12 +186,4 @@ mod tests {
 
         assert_eq!(count, 6);
     }
-
-    #[test]
-    fn test_2() {
-        let mut grid = parse(INPUT).unwrap();
-        let count = cycles(&mut grid);
-
-        assert_eq!(count, 1_770);
-    }
 }
@@ -1,5 +1,8 @@
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 use itertools::{repeat_n, Itertools};
+use std::fmt::Display;
+use std::ops::{Add, Mul};
 
 pub fn parse(input: &str) -> Result<Vec<(u64, Vec<u64>)>> {
     input
@@ -53,6 +56,24 @@ pub fn concat(a: u64, b: u64) -> u64 {
     a * 10u64.pow(num_digits) + b
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(equations: Self::Input<'_>) -> Result<impl Display> {
+        Ok(solve(&equations, &[Add::add, Mul::mul]))
+    }
+
+    fn part2(equations: Self::Input<'_>) -> Result<impl Display> {
+        Ok(solve(&equations, &[Add::add, Mul::mul, concat]))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,7 +90,6 @@ mod tests {
 21037: 9 7 18 13
 292: 11 6 16 20
 ";
-    const INPUT: &str = include_str!("../../input/2024/07.txt");
 
     #[test]
     fn test_1_sample() {
@@ -79,14 +99,6 @@ mod tests {
         assert_eq!(sum, 3_749);
     }
 
-    #[test]
-    fn test_1() {
-        let input = parse(INPUT).unwrap();
-        let sum = solve(&input, &[Add::add, Mul::mul]);
-
-        assert_eq!(sum, 1_298_300_076_754);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = parse(SAMPLE).unwrap();
@@ -94,12 +106,4 @@ mod tests {
 
         assert_eq!(sum, 11_387);
     }
-
-    #[test]
-    fn test_2() {
-        let input = parse(INPUT).unwrap();
-        let sum = solve(&input, &[Add::add, Mul::mul, concat]);
-
-        assert_eq!(sum, 248_427_118_972_289);
-    }
 }
@@ -1,6 +1,8 @@
+use crate::solution::Solution;
 use anyhow::Result;
 use itertools::Itertools;
 use std::collections::HashMap;
+use std::fmt::Display;
 
 pub struct Grid {
     pub width: i32,
@@ -74,6 +76,24 @@ pub fn solve2(input: &Grid) -> usize {
         .count()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(grid: Self::Input<'_>) -> Result<impl Display> {
+        Ok(solve(&grid))
+    }
+
+    fn part2(grid: Self::Input<'_>) -> Result<impl Display> {
+        Ok(solve2(&grid))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,8 +126,6 @@ T.........
 ..........
 ";
 
-    const INPUT: &str = include_str!("../../input/2024/08.txt");
-
     #[test]
     fn test_1_sample() {
         let input = parse(SAMPLE).unwrap();
@@ -116,14 +134,6 @@ T.........
         assert_eq!(result, 14);
     }
 
-    #[test]
-    fn test_1() {
-        let input = parse(INPUT).unwrap();
-        let result = solve(&input);
-
-        assert_eq!(result, 332);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = parse(SAMPLE).unwrap();
@@ -139,12 +149,4 @@ T.........
 
         assert_eq!(result, 9);
     }
-
-    #[test]
-    fn test_2() {
-        let input = parse(INPUT).unwrap();
-        let result = solve2(&input);
-
-        assert_eq!(result, 1_174);
-    }
 }
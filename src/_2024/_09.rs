@@ -1,5 +1,7 @@
+use crate::solution::Solution;
 use anyhow::Result;
 use itertools::Itertools;
+use std::fmt::Display;
 
 pub struct Disk {
     pub blocks: Vec<Block>,
@@ -112,12 +114,29 @@ impl Disk {
     }
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Disk;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(mut disk: Self::Input<'_>) -> Result<impl Display> {
+        Ok(disk.defrag_blocks())
+    }
+
+    fn part2(mut disk: Self::Input<'_>) -> Result<impl Display> {
+        Ok(disk.defrag_groups())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     const SAMPLE: &str = "2333133121414131402";
-    const INPUT: &str = include_str!("../../input/2024/09.txt");
 
     #[test]
     fn test_1_sample() {
@@ -126,24 +145,10 @@ mod tests {
         assert_eq!(disk.defrag_blocks(), 1_928);
     }
 
-    #[test]
-    fn test_1() {
-        let mut disk = parse(INPUT).unwrap();
-
-        assert_eq!(disk.defrag_blocks(), 6_201_130_364_722);
-    }
-
     #[test]
     fn test_2_sample() {
         let mut disk = parse(SAMPLE).unwrap();
 
         assert_eq!(disk.defrag_groups(), 2_858);
     }
-
-    #[test]
-    fn test_2() {
-        let mut disk = parse(INPUT).unwrap();
-
-        assert_eq!(disk.defrag_groups(), 6_221_662_795_602);
-    }
 }
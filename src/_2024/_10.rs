@@ -1,58 +1,15 @@
+use crate::grid::{Grid, Point};
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
-use std::collections::{HashMap, HashSet};
-
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Point {
-    pub fn neighbors(self) -> [Point; 4] {
-        [
-            Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-            Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-        ]
-    }
-}
-
-pub fn parse(input: &str) -> Result<HashMap<Point, u8>> {
-    let mut grid = HashMap::new();
-    for (y, line) in input.lines().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            if let Some(value) = ch.to_digit(10) {
-                grid.insert(
-                    Point {
-                        x: x as i32,
-                        y: y as i32,
-                    },
-                    value as u8,
-                );
-            } else {
-                return Err(anyhow!(
-                    "Invalid character in input at line {}, column {}: '{}'",
-                    y,
-                    x,
-                    ch
-                ));
-            }
-        }
-    }
-    Ok(grid)
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub fn parse(input: &str) -> Result<Grid<u8>> {
+    Grid::parse_with(input, |ch| {
+        ch.to_digit(10)
+            .map(|value| value as u8)
+            .ok_or_else(|| anyhow!("Invalid character in input: '{ch}'"))
+    })
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -61,9 +18,9 @@ pub enum ScoringMethod {
     UniquePaths,
 }
 
-pub fn calculate_path_scores(grid: &HashMap<Point, u8>, scoring_method: ScoringMethod) -> usize {
+pub fn calculate_path_scores(grid: &Grid<u8>, scoring_method: ScoringMethod) -> usize {
     fn traverse_trail(
-        grid: &HashMap<Point, u8>,
+        grid: &Grid<u8>,
         trail_head: Point,
         start_elevation: u8,
     ) -> (HashSet<Point>, usize) {
@@ -72,7 +29,7 @@ pub fn calculate_path_scores(grid: &HashMap<Point, u8>, scoring_method: ScoringM
         let mut stack = vec![(trail_head, start_elevation)];
         while let Some((point, elevation)) = stack.pop() {
             for neighbor in point.neighbors() {
-                if let Some(&neighbor_value) = grid.get(&neighbor) {
+                if let Some(&neighbor_value) = grid.get(neighbor) {
                     if neighbor_value == elevation + 1 {
                         stack.push((neighbor, neighbor_value));
                     }
@@ -90,7 +47,7 @@ pub fn calculate_path_scores(grid: &HashMap<Point, u8>, scoring_method: ScoringM
 
     grid.iter()
         .filter(|(_, &value)| value == 0)
-        .map(|(&trail_head, &value)| {
+        .map(|(trail_head, &value)| {
             let (p1, p2) = traverse_trail(grid, trail_head, value);
 
             match scoring_method {
@@ -101,6 +58,24 @@ pub fn calculate_path_scores(grid: &HashMap<Point, u8>, scoring_method: ScoringM
         .sum()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(grid: Self::Input<'_>) -> Result<impl Display> {
+        Ok(calculate_path_scores(&grid, ScoringMethod::Unique9s))
+    }
+
+    fn part2(grid: Self::Input<'_>) -> Result<impl Display> {
+        Ok(calculate_path_scores(&grid, ScoringMethod::UniquePaths))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,7 +90,6 @@ mod tests {
 01329801
 10456732
 ";
-    const INPUT: &str = include_str!("../../input/2024/10.txt");
 
     #[test]
     fn test_1_sample() {
@@ -125,14 +99,6 @@ mod tests {
         assert_eq!(score, 36);
     }
 
-    #[test]
-    fn test_1() {
-        let input = parse(INPUT).unwrap();
-        let score = calculate_path_scores(&input, ScoringMethod::Unique9s);
-
-        assert_eq!(score, 841);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = parse(SAMPLE).unwrap();
@@ -140,12 +106,4 @@ mod tests {
 
         assert_eq!(score, 81);
     }
-
-    #[test]
-    fn test_2() {
-        let input = parse(INPUT).unwrap();
-        let score = calculate_path_scores(&input, ScoringMethod::UniquePaths);
-
-        assert_eq!(score, 1_875);
-    }
 }
@@ -1,5 +1,7 @@
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 use std::collections::HashMap;
+use std::fmt::Display;
 
 pub fn parse(input: &str) -> Result<Vec<u64>> {
     input
@@ -63,12 +65,29 @@ pub fn blink(stones: &[u64], times: usize) -> usize {
         .sum()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(stones: Self::Input<'_>) -> Result<impl Display> {
+        Ok(blink(&stones, 25))
+    }
+
+    fn part2(stones: Self::Input<'_>) -> Result<impl Display> {
+        Ok(blink(&stones, 75))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     const SAMPLE: &str = "125 17";
-    const INPUT: &str = include_str!("../../input/2024/11.txt");
 
     #[test]
     fn test_1_sample() {
@@ -78,14 +97,6 @@ mod tests {
         assert_eq!(stones, 55_312);
     }
 
-    #[test]
-    fn test_1() {
-        let stones = parse(INPUT).unwrap();
-        let stones = blink(&stones, 25);
-
-        assert_eq!(stones, 200_446);
-    }
-
     #[test]
     fn test_2_sample() {
         let stones = parse(SAMPLE).unwrap();
@@ -93,12 +104,4 @@ mod tests {
 
         assert_eq!(stones, 65_601_038_650_482);
     }
-
-    #[test]
-    fn test_2() {
-        let stones = parse(INPUT).unwrap();
-        let stones = blink(&stones, 75);
-
-        assert_eq!(stones, 238_317_474_993_392);
-    }
 }
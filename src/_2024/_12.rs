@@ -1,126 +1,38 @@
+use crate::grid::{Direction8, Grid, Point};
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
-use std::collections::{HashMap, HashSet, VecDeque};
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Point {
-    /// Returns the neighbors of the point (N, S, E, W)
-    pub fn neighbors(&self) -> [Point; 4] {
-        [
-            Point {
-                x: self.x,
-                y: self.y - 1,
-            }, // North
-            Point {
-                x: self.x,
-                y: self.y + 1,
-            }, // South
-            Point {
-                x: self.x + 1,
-                y: self.y,
-            }, // East
-            Point {
-                x: self.x - 1,
-                y: self.y,
-            }, // West
-        ]
-    }
-
-    // returns the 3 Points diagonal to this point
-    // in order to check if it is a corner.
-    // eg N+NE+E, S+SW+W
-    pub fn corners(&self) -> [[Point; 3]; 4] {
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+
+/// Returns the 3 points around each corner of a point, with the diagonal one in the middle, in
+/// order to check if it is a corner.
+/// eg N+NE+E, S+SW+W
+pub fn corners_of(point: Point) -> [[Point; 3]; 4] {
+    [
+        Direction8::UpRight,
+        Direction8::DownRight,
+        Direction8::DownLeft,
+        Direction8::UpLeft,
+    ]
+    .map(|diagonal| {
         [
-            [
-                Point {
-                    x: self.x,
-                    y: self.y - 1,
-                },
-                Point {
-                    x: self.x + 1,
-                    y: self.y - 1,
-                },
-                Point {
-                    x: self.x + 1,
-                    y: self.y,
-                },
-            ], // NE
-            [
-                Point {
-                    x: self.x,
-                    y: self.y - 1,
-                },
-                Point {
-                    x: self.x - 1,
-                    y: self.y - 1,
-                },
-                Point {
-                    x: self.x - 1,
-                    y: self.y,
-                },
-            ], // NW
-            [
-                Point {
-                    x: self.x + 1,
-                    y: self.y,
-                },
-                Point {
-                    x: self.x + 1,
-                    y: self.y + 1,
-                },
-                Point {
-                    x: self.x,
-                    y: self.y + 1,
-                },
-            ], // SE
-            [
-                Point {
-                    x: self.x - 1,
-                    y: self.y,
-                },
-                Point {
-                    x: self.x - 1,
-                    y: self.y + 1,
-                },
-                Point {
-                    x: self.x,
-                    y: self.y + 1,
-                },
-            ], // SW
+            point + diagonal.turn_left(),
+            point + diagonal,
+            point + diagonal.turn_right(),
         ]
-    }
+    })
 }
 
-pub fn parse(input: &str) -> HashMap<Point, char> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(x, c)| {
-                    (
-                        Point {
-                            x: x as i32,
-                            y: y as i32,
-                        },
-                        c,
-                    )
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect()
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    input.parse()
 }
 
-pub fn find_vegetable_plots(grid: &HashMap<Point, char>) -> Vec<(Vec<Point>, char)> {
+pub fn find_vegetable_plots(grid: &Grid<char>) -> Vec<(Vec<Point>, char)> {
     let mut visited = HashSet::new();
     let mut plots: Vec<(Vec<Point>, char)> = Vec::new();
 
-    for (&point, &value) in grid {
+    for (point, &value) in grid.iter() {
         if visited.contains(&point) {
             continue; // Already visited this point
         }
@@ -139,7 +51,7 @@ pub fn find_vegetable_plots(grid: &HashMap<Point, char>) -> Vec<(Vec<Point>, cha
 
             for neighbor in current.neighbors() {
                 if !visited.contains(&neighbor) {
-                    if let Some(&neighbor_value) = grid.get(&neighbor) {
+                    if let Some(&neighbor_value) = grid.get(neighbor) {
                         if neighbor_value == value {
                             queue.push_front(neighbor);
                         }
@@ -156,25 +68,26 @@ pub fn find_vegetable_plots(grid: &HashMap<Point, char>) -> Vec<(Vec<Point>, cha
     plots
 }
 
-pub fn perimeter(grid: &HashMap<Point, char>, group: &[Point], vegetable: char) -> usize {
+pub fn perimeter(grid: &Grid<char>, group: &[Point], vegetable: char) -> usize {
     group
         .iter()
         .map(|point| {
             4 - point
                 .neighbors()
                 .iter()
-                .filter(|p| grid.get(p).is_some_and(|v| *v == vegetable))
+                .filter(|p| grid.get(**p).is_some_and(|v| *v == vegetable))
                 .count()
         })
         .sum()
 }
 
-pub fn count_corners(grid: &HashMap<Point, char>, plot: &[Point], vegetable: char) -> usize {
+pub fn count_corners(grid: &Grid<char>, plot: &[Point], vegetable: char) -> usize {
     let mut corners = 0;
 
     for point in plot {
-        for corner in point.corners() {
-            let (left, corner, right) = corner.iter().map(|p| grid.get(p)).collect_tuple().unwrap();
+        for corner in corners_of(*point) {
+            let (left, corner, right) =
+                corner.iter().map(|&p| grid.get(p)).collect_tuple().unwrap();
 
             // ...
             // VV.
@@ -198,7 +111,7 @@ pub fn count_corners(grid: &HashMap<Point, char>, plot: &[Point], vegetable: cha
     corners
 }
 
-pub fn sum_perimeter_area(grid: &HashMap<Point, char>, groupings: &[(Vec<Point>, char)]) -> usize {
+pub fn sum_perimeter_area(grid: &Grid<char>, groupings: &[(Vec<Point>, char)]) -> usize {
     groupings
         .iter()
         .map(|(group, vegetable)| {
@@ -209,7 +122,7 @@ pub fn sum_perimeter_area(grid: &HashMap<Point, char>, groupings: &[(Vec<Point>,
         .sum()
 }
 
-pub fn sum_sides_area(grid: &HashMap<Point, char>, plots: &[(Vec<Point>, char)]) -> usize {
+pub fn sum_sides_area(grid: &Grid<char>, plots: &[(Vec<Point>, char)]) -> usize {
     plots
         .iter()
         .map(|(plot, vegetable)| {
@@ -220,6 +133,26 @@ pub fn sum_sides_area(grid: &HashMap<Point, char>, plots: &[(Vec<Point>, char)])
         .sum()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(grid: Self::Input<'_>) -> Result<impl Display> {
+        let plots = find_vegetable_plots(&grid);
+        Ok(sum_perimeter_area(&grid, &plots))
+    }
+
+    fn part2(grid: Self::Input<'_>) -> Result<impl Display> {
+        let plots = find_vegetable_plots(&grid);
+        Ok(sum_sides_area(&grid, &plots))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,29 +197,19 @@ OOOOO
 OXOXO
 OOOOO
 ";
-    const INPUT: &str = include_str!("../../input/2024/12.txt");
 
     #[test]
     fn test_1_sample() {
-        let input = parse(SAMPLE);
+        let input = parse(SAMPLE).unwrap();
         let plots = find_vegetable_plots(&input);
         let sum = sum_perimeter_area(&input, &plots);
 
         assert_eq!(sum, 1_930);
     }
 
-    #[test]
-    fn test_1() {
-        let input = parse(INPUT);
-        let plots = find_vegetable_plots(&input);
-        let sum = sum_perimeter_area(&input, &plots);
-
-        assert_eq!(sum, 1_421_958);
-    }
-
     #[test]
     fn test_2_sample() {
-        let input = parse(SAMPLE);
+        let input = parse(SAMPLE).unwrap();
         let plots = find_vegetable_plots(&input);
         let sum = sum_sides_area(&input, &plots);
 
@@ -295,7 +218,7 @@ OOOOO
 
     #[test]
     fn test_2_sample_2() {
-        let input = parse(SAMPLE_2);
+        let input = parse(SAMPLE_2).unwrap();
         let plots = find_vegetable_plots(&input);
         let sum = sum_sides_area(&input, &plots);
 
@@ -304,7 +227,7 @@ OOOOO
 
     #[test]
     fn test_2_sample_3() {
-        let input = parse(SAMPLE_3);
+        let input = parse(SAMPLE_3).unwrap();
         let plots = find_vegetable_plots(&input);
         let sum = sum_sides_area(&input, &plots);
 
@@ -313,7 +236,7 @@ OOOOO
 
     #[test]
     fn test_2_sample_4() {
-        let input = parse(SAMPLE_4);
+        let input = parse(SAMPLE_4).unwrap();
         let plots = find_vegetable_plots(&input);
         let sum = sum_sides_area(&input, &plots);
 
@@ -322,19 +245,10 @@ OOOOO
 
     #[test]
     fn test_2_sample_5() {
-        let input = parse(SAMPLE_5);
+        let input = parse(SAMPLE_5).unwrap();
         let plots = find_vegetable_plots(&input);
         let sum = sum_sides_area(&input, &plots);
 
         assert_eq!(sum, 436);
     }
-
-    #[test]
-    fn test_2() {
-        let input = parse(INPUT);
-        let plots = find_vegetable_plots(&input);
-        let sum = sum_sides_area(&input, &plots);
-
-        assert_eq!(sum, 885_394);
-    }
 }
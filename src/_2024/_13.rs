@@ -1,5 +1,8 @@
+use crate::math;
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
+use std::fmt::Display;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Button {
@@ -53,39 +56,42 @@ pub fn parse(input: &str) -> Result<Vec<Machine>> {
     machines
 }
 
-fn determinant(a: i64, b: i64, c: i64, d: i64) -> i64 {
-    // | a b |
-    // |  x  |
-    // | c d |
-    a * d - b * c
-}
-
 impl Machine {
     // Cramer's rule for solving a system of two equations, apparently.
     // IDK I never watched Seinfeld
     //
     // This only works because there is either 0 or 1 solution for the Machine.
     pub fn min_tokens(&self) -> i64 {
-        // | ax bx |
-        // | ay by |
-        let d = determinant(self.a.x, self.b.x, self.a.y, self.b.y);
-        // | px py |
-        // | bx by |
-        let a_times = determinant(self.prize.x, self.prize.y, self.b.x, self.b.y) / d;
-        // | ax ay |
-        // | px py |
-        let b_times = determinant(self.a.x, self.a.y, self.prize.x, self.prize.y) / d;
-
-        if self.press(a_times, b_times) == (self.prize.x, self.prize.y) {
-            (a_times * 3) + b_times
-        } else {
-            0
-        }
+        math::solve_2x2(
+            [[self.a.x, self.b.x], [self.a.y, self.b.y]],
+            [self.prize.x, self.prize.y],
+        )
+        .map_or(0, |[a_times, b_times]| (a_times * 3) + b_times)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
     }
 
-    #[inline]
-    fn press(&self, a: i64, b: i64) -> (i64, i64) {
-        (self.a.x * a + self.b.x * b, self.a.y * a + self.b.y * b)
+    fn part1(machines: Self::Input<'_>) -> Result<impl Display> {
+        Ok(machines.iter().map(Machine::min_tokens).sum::<i64>())
+    }
+
+    fn part2(machines: Self::Input<'_>) -> Result<impl Display> {
+        Ok(machines
+            .into_iter()
+            .map(|mut machine| {
+                machine.prize.x += 10_000_000_000_000;
+                machine.prize.y += 10_000_000_000_000;
+                machine.min_tokens()
+            })
+            .sum::<i64>())
     }
 }
 
@@ -110,7 +116,6 @@ Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279
 ";
-    const INPUT: &str = include_str!("../../input/2024/13.txt");
 
     #[test]
     fn test_1_sample() {
@@ -119,27 +124,4 @@ Prize: X=18641, Y=10279
 
         assert_eq!(min_tokens, 480);
     }
-
-    #[test]
-    fn test_1() {
-        let input = parse(INPUT).unwrap();
-        let min_tokens: i64 = input.iter().map(Machine::min_tokens).sum();
-
-        assert_eq!(min_tokens, 28_753);
-    }
-
-    #[test]
-    fn test_2() {
-        let mut input = parse(INPUT).unwrap();
-        let min_tokens: i64 = input
-            .iter_mut()
-            .map(|machine| {
-                machine.prize.x += 10_000_000_000_000;
-                machine.prize.y += 10_000_000_000_000;
-                machine.min_tokens()
-            })
-            .sum();
-
-        assert_eq!(min_tokens, 102_718_967_795_500);
-    }
 }
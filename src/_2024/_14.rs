@@ -1,6 +1,9 @@
+use crate::math;
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 use gif::{Encoder, Frame, Repeat};
 use std::collections::HashSet;
+use std::fmt::Display;
 use std::fs::File;
 
 pub fn parse(input: &str) -> Result<Vec<(i32, i32, i32, i32)>> {
@@ -72,12 +75,11 @@ pub fn p1(mut roombas: Vec<(i32, i32, i32, i32)>, times: i32, width: i32, height
     q1 * q2 * q3 * q4
 }
 
-//  This solution only works because of an abstract property of the puzzle where no robots overlap
-// in the solution. I initially guessed that all robots would be used in the formation of the tree, tried it,
-// and got the correct number.
-//
-// It is not true that all robots are used though, so I feel like that is a mistake in the input generation.
-// I imagine that the intent behind this puzzle was some sort of cluster search algorithm given the quadrants solution in p1.
+// The x positions of the robots repeat every `width` seconds and the y positions every `height`
+// seconds. The tree is drawn where the robots bunch up, so this finds the second in each cycle
+// where the robots are least spread out along that axis, and the Chinese Remainder Theorem gives
+// the second where both happen at once. Not every robot is part of the tree, so looking for the
+// first second where no robots overlap only finds it because of how the input was generated.
 //
 // ###############################
 // #.............................#
@@ -112,29 +114,34 @@ pub fn p1(mut roombas: Vec<(i32, i32, i32, i32)>, times: i32, width: i32, height
 // #.............................#
 // #.............................#
 // ###############################
-pub fn p2(mut roombas: Vec<(i32, i32, i32, i32)>) -> usize {
-    let width = 101;
-    let height = 103;
-    let mut seconds = 0;
-    let mut seen = HashSet::new();
-
-    'outer: loop {
-        seconds += 1;
-        seen.clear();
-        for roomba in &mut roombas {
-            advance(roomba, 1, width, height);
-        }
-        // significant speed improvement, about 3-4x
-        // as soon as a single overlap occurs, re-loop.
-        for &(x, y, ..) in &roombas {
-            let new = seen.insert((x, y));
-            if !new {
-                continue 'outer;
-            }
-        }
-        break;
+pub fn p2(roombas: &[(i32, i32, i32, i32)], width: i32, height: i32) -> Option<i64> {
+    // sum of the squared distances from the mean, which is smallest when the robots bunch up
+    fn spread(positions: impl Iterator<Item = i32> + Clone) -> i64 {
+        let count = positions.clone().count() as i64;
+        let mean = positions.clone().map(i64::from).sum::<i64>() / count.max(1);
+        positions.map(|p| (i64::from(p) - mean).pow(2)).sum()
     }
-    seconds
+
+    let x_second = (0..width).min_by_key(|&seconds| {
+        spread(
+            roombas
+                .iter()
+                .map(move |r| (r.0 + r.2 * seconds).rem_euclid(width)),
+        )
+    })?;
+    let y_second = (0..height).min_by_key(|&seconds| {
+        spread(
+            roombas
+                .iter()
+                .map(move |r| (r.1 + r.3 * seconds).rem_euclid(height)),
+        )
+    })?;
+
+    math::crt(&[
+        (i64::from(x_second), i64::from(width)),
+        (i64::from(y_second), i64::from(height)),
+    ])
+    .map(|(seconds, _)| seconds)
 }
 
 // this function created the gif neighboring this file
@@ -197,6 +204,25 @@ pub fn write_file(mut roombas: Vec<(i32, i32, i32, i32)>) {
         }
     }
 }
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<(i32, i32, i32, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(roombas: Self::Input<'_>) -> Result<impl Display> {
+        Ok(p1(roombas, 100, 101, 103))
+    }
+
+    fn part2(roombas: Self::Input<'_>) -> Result<impl Display> {
+        p2(&roombas, 101, 103).ok_or_else(|| anyhow!("the robots never line up"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,7 +241,6 @@ p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3
 ";
-    const INPUT: &str = include_str!("../../input/2024/14.txt");
 
     #[test]
     fn test_1_sample() {
@@ -225,18 +250,18 @@ p=9,5 v=-3,-3
     }
 
     #[test]
-    fn test_1() {
-        let roombas = parse(INPUT).unwrap();
-        let answer = p1(roombas, 100, 101, 103);
-
-        assert_eq!(answer, 226_179_492);
-    }
-
-    #[test]
-    fn test_2() {
-        let roombas = parse(INPUT).unwrap();
-        let seconds = p2(roombas);
+    fn test_2_synthetic() {
+        // robots that all meet at (2, 3) after 12 seconds, on a grid with coprime sides. Their
+        // velocities differ along both axes, so they are spread out at every other second
+        let (width, height, seconds): (i32, i32, i32) = (5, 7, 12);
+        let roombas: Vec<_> = (-3..=3)
+            .map(|v| {
+                let x = (2 - v * seconds).rem_euclid(width);
+                let y = (3 - v * seconds).rem_euclid(height);
+                (x, y, v, v)
+            })
+            .collect();
 
-        assert_eq!(seconds, 7_502);
+        assert_eq!(p2(&roombas, width, height), Some(i64::from(seconds)));
     }
 }
@@ -1,87 +1,15 @@
+use crate::grid::{Direction, Grid, Point};
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
+use std::collections::HashSet;
 use std::collections::VecDeque;
-use std::collections::{HashMap, HashSet};
-use std::ops::{Add, Sub};
+use std::fmt::Display;
 
-#[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
-pub struct Point {
-    x: i32,
-    y: i32,
-}
-impl Sub<Direction> for Point {
-    type Output = Point;
-
-    fn sub(self, direction: Direction) -> Self::Output {
-        match direction {
-            Direction::Up => Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Down => Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Left => Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-            Direction::Right => Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-        }
-    }
-}
-impl Add<Direction> for Point {
-    type Output = Point;
-
-    fn add(self, direction: Direction) -> Point {
-        match direction {
-            Direction::Up => Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Down => Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Left => Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::Right => Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
-    }
-}
-
-#[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    pub fn from_char(c: char) -> Result<Self> {
-        match c {
-            '^' => Ok(Direction::Up),
-            'v' => Ok(Direction::Down),
-            '<' => Ok(Direction::Left),
-            '>' => Ok(Direction::Right),
-            _ => Err(anyhow!("char {c} is not a direction")),
-        }
-    }
-
-    pub fn vec_from_str(s: &str) -> Result<Vec<Self>> {
-        s.chars()
-            .filter(|c| !c.is_whitespace())
-            .map(Self::from_char)
-            .collect()
-    }
+pub fn parse_directions(s: &str) -> Result<Vec<Direction>> {
+    s.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| Direction::from_arrow(c).ok_or_else(|| anyhow!("char {c} is not a direction")))
+        .collect()
 }
 
 #[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
@@ -94,19 +22,18 @@ pub enum Tile {
     BoxRight,
 }
 
-impl std::str::FromStr for Tile {
-    type Err = anyhow::Error;
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.chars().next() {
-            Some('@') => Ok(Tile::Robot),
-            Some('#') => Ok(Tile::Wall),
-            Some('.') => Ok(Tile::Empty),
-            Some('O') => Ok(Tile::Box),
-            Some('[') => Ok(Tile::BoxLeft),
-            Some(']') => Ok(Tile::BoxRight),
-            Some(c) => Err(anyhow!("unexpected character: {c}")),
-            None => Err(anyhow!("empty string")),
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '@' => Ok(Tile::Robot),
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Empty),
+            'O' => Ok(Tile::Box),
+            '[' => Ok(Tile::BoxLeft),
+            ']' => Ok(Tile::BoxRight),
+            c => Err(anyhow!("unexpected character: {c}")),
         }
     }
 }
@@ -127,71 +54,26 @@ impl std::fmt::Display for Tile {
 
 #[derive(Debug)]
 pub struct Warehouse {
-    grid: HashMap<Point, Tile>,
+    grid: Grid<Tile>,
     directions: Vec<Direction>,
 }
 
 impl Warehouse {
     pub fn expand(&mut self) {
-        let mut new_grid = HashMap::new();
-
-        for (point, tile) in &self.grid {
-            let point = Point {
-                x: point.x * 2,
-                y: point.y,
-            };
-            match tile {
-                Tile::Wall => {
-                    // Add two walls
-                    new_grid.insert(point, Tile::Wall);
-                    new_grid.insert(
-                        Point {
-                            x: point.x + 1,
-                            y: point.y,
-                        },
-                        Tile::Wall,
-                    );
-                }
-                Tile::Robot => {
-                    // Add Robot and Empty
-                    new_grid.insert(point, Tile::Robot);
-                    new_grid.insert(
-                        Point {
-                            x: point.x + 1,
-                            y: point.y,
-                        },
-                        Tile::Empty,
-                    );
-                }
-                Tile::Box => {
-                    // Add BoxLeft and BoxRight as Box for simplicity (adjust if further enums are needed)
-                    new_grid.insert(point, Tile::BoxLeft);
-                    new_grid.insert(
-                        Point {
-                            x: point.x + 1,
-                            y: point.y,
-                        },
-                        Tile::BoxRight,
-                    );
-                }
-                Tile::Empty => {
-                    // Add two Empty tiles
-                    new_grid.insert(point, Tile::Empty);
-                    new_grid.insert(
-                        Point {
-                            x: point.x + 1,
-                            y: point.y,
-                        },
-                        Tile::Empty,
-                    );
-                }
+        let grid = &self.grid;
+        self.grid = Grid::from_fn(grid.width() * 2, grid.height(), |point| {
+            let tile = grid[Point::new(point.x / 2, point.y)];
+            let left_half = point.x % 2 == 0;
+            match (tile, left_half) {
+                (Tile::Wall, _) => Tile::Wall,
+                (Tile::Robot, true) => Tile::Robot,
+                (Tile::Box, true) => Tile::BoxLeft,
+                (Tile::Box, false) => Tile::BoxRight,
+                (Tile::Robot | Tile::Empty, _) => Tile::Empty,
                 // these tiles do not exist pre-expansion
-                Tile::BoxLeft | Tile::BoxRight => unreachable!(),
+                (Tile::BoxLeft | Tile::BoxRight, _) => unreachable!(),
             }
-        }
-
-        // Update the warehouse grid with the expanded one
-        self.grid = new_grid;
+        });
     }
     pub fn advance_robot(&mut self) {
         let mut robot_position = self
@@ -205,7 +87,7 @@ impl Warehouse {
             loop {
                 let new_position = current_position + *direction;
 
-                if let Some(tile) = self.grid.get(&new_position) {
+                if let Some(tile) = self.grid.get(new_position) {
                     match tile {
                         Tile::Wall => break, // Stop when a wall is encountered
                         Tile::Box => potential_moves.push((new_position, *tile)),
@@ -227,23 +109,16 @@ impl Warehouse {
             }
 
             for (position, _) in potential_moves[1..].iter().rev() {
-                self.grid.insert(*position, Tile::Box);
+                self.grid[*position] = Tile::Box;
             }
-            self.grid
-                .insert(potential_moves.first().unwrap().0, Tile::Robot);
-            self.grid.insert(robot_position, Tile::Empty);
+            self.grid[potential_moves.first().unwrap().0] = Tile::Robot;
+            self.grid[robot_position] = Tile::Empty;
             robot_position = potential_moves.first().unwrap().0;
         }
     }
 
     fn robot_position(&self) -> Option<Point> {
-        self.grid.iter().find_map(|(point, tile)| {
-            if *tile == Tile::Robot {
-                Some(*point)
-            } else {
-                None
-            }
-        })
+        self.grid.find(&Tile::Robot)
     }
 
     // similar logic to p1 for left and right. Just move every position over
@@ -264,10 +139,7 @@ impl Warehouse {
                 queue.push_back(robot_position);
 
                 while let Some(current_position) = queue.pop_front() {
-                    let current_tile = self
-                        .grid
-                        .get(&current_position)
-                        .expect("all positions should be in the grid");
+                    let current_tile = self.grid[current_position];
                     match current_tile {
                         Tile::Empty => {
                             break;
@@ -285,13 +157,8 @@ impl Warehouse {
                     }
                 }
                 for position in &pieces_to_move {
-                    let current_tile = *self
-                        .grid
-                        .get(position)
-                        .expect("every position to move should be in the grid");
-
-                    self.grid.insert(*position + *direction, current_tile);
-                    self.grid.insert(*position, Tile::Empty);
+                    self.grid[*position + *direction] = self.grid[*position];
+                    self.grid[*position] = Tile::Empty;
                 }
             } else {
                 // BFS
@@ -307,10 +174,7 @@ impl Warehouse {
                     if !seen.insert(current_position) {
                         continue;
                     }
-                    let current_tile = self
-                        .grid
-                        .get(&current_position)
-                        .expect("all positions should be in the grid");
+                    let current_tile = self.grid[current_position];
                     match current_tile {
                         Tile::Robot => {
                             pieces_to_move.push(current_position);
@@ -345,13 +209,8 @@ impl Warehouse {
                     _ => unreachable!("Direction should only be Up or Down in this context"),
                 });
                 for position in &pieces_to_move {
-                    let current_tile = *self
-                        .grid
-                        .get(position)
-                        .expect("every position to move should be in the grid");
-
-                    self.grid.insert(*position + *direction, current_tile);
-                    self.grid.insert(*position, Tile::Empty);
+                    self.grid[*position + *direction] = self.grid[*position];
+                    self.grid[*position] = Tile::Empty;
                 }
             }
         }
@@ -372,18 +231,7 @@ impl Warehouse {
 
 impl std::fmt::Display for Warehouse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let max_x = self.grid.keys().map(|p| p.x).max().unwrap_or(0);
-        let max_y = self.grid.keys().map(|p| p.y).max().unwrap_or(0);
-
-        for y in 0..=max_y {
-            for x in 0..=max_x {
-                let tile = self.grid.get(&Point { x, y }).unwrap_or(&Tile::Empty);
-                write!(f, "{tile}")?;
-            }
-            writeln!(f)?; // Newline after each row
-        }
-
-        Ok(())
+        writeln!(f, "{}", self.grid)
     }
 }
 
@@ -395,21 +243,31 @@ impl std::str::FromStr for Warehouse {
             .split_once("\n\n")
             .ok_or(anyhow!("could not split input into grid and directions"))?;
 
-        let mut grid = HashMap::new();
+        let grid = grid_str.parse()?;
+        let directions = parse_directions(directions)?;
 
-        for (y, line) in grid_str.lines().enumerate() {
-            for (x, ch) in line.chars().enumerate() {
-                let point = Point {
-                    x: x as i32,
-                    y: y as i32,
-                };
-                grid.insert(point, Tile::from_str(&ch.to_string())?);
-            }
-        }
+        Ok(Warehouse { grid, directions })
+    }
+}
 
-        let directions = Direction::vec_from_str(directions)?;
+pub struct Day;
 
-        Ok(Warehouse { grid, directions })
+impl Solution for Day {
+    type Input<'a> = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse()
+    }
+
+    fn part1(mut warehouse: Self::Input<'_>) -> Result<impl Display> {
+        warehouse.advance_robot();
+        Ok(warehouse.sum_gps())
+    }
+
+    fn part2(mut warehouse: Self::Input<'_>) -> Result<impl Display> {
+        warehouse.expand();
+        warehouse.advance_robot_expanded();
+        Ok(warehouse.sum_gps())
     }
 }
 
@@ -479,7 +337,6 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 ####################
 
 ^";
-    const INPUT: &str = include_str!("../../input/2024/15.txt");
 
     #[test]
     fn test_1_sample() {
@@ -497,14 +354,6 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
         assert_eq!(warehouse.sum_gps(), 2_028);
     }
 
-    #[test]
-    fn test_1() {
-        let mut warehouse: Warehouse = INPUT.parse().unwrap();
-        warehouse.advance_robot();
-
-        assert_eq!(warehouse.sum_gps(), 1_437_174);
-    }
-
     #[test]
     fn test_2_sample() {
         let mut warehouse: Warehouse = SAMPLE.parse().unwrap();
@@ -529,13 +378,4 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 
         assert_eq!(warehouse.sum_gps(), 9_021);
     }
-
-    #[test]
-    fn test_2() {
-        let mut warehouse: Warehouse = INPUT.parse().unwrap();
-        warehouse.expand();
-        warehouse.advance_robot_expanded();
-
-        assert_eq!(warehouse.sum_gps(), 1_437_468);
-    }
 }
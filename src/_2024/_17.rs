@@ -1,4 +1,9 @@
+use crate::solution::Solution;
+use anyhow::{bail, ensure, Context, Result};
 use itertools::Itertools;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone)]
 pub struct Computer {
@@ -11,104 +16,200 @@ pub struct Computer {
 }
 
 impl Computer {
-    fn literal_op(&self) -> u64 {
-        self.instructions[self.instruction_pointer + 1]
-    }
-    fn combo_op(&self) -> u64 {
-        let op = self.instructions[self.instruction_pointer + 1];
-        match op {
-            0..=3 => op,
-            4 => self.reg_a,
-            5 => self.reg_b,
-            6 => self.reg_c,
-            7 => panic!("7 is not a valid combo op"),
-            unhandled => panic!("{unhandled:?} is an unknown opcode"),
+    pub fn registers(&self) -> Registers {
+        Registers {
+            a: self.reg_a,
+            b: self.reg_b,
+            c: self.reg_c,
         }
     }
-    fn adv(&mut self) {
-        let num = self.reg_a;
-        let denom = 2_u64.pow(self.combo_op() as u32);
-        self.reg_a = num / denom;
-        self.instruction_pointer += 2;
-    }
-    fn bxl(&mut self) {
-        self.reg_b ^= self.literal_op();
-        self.instruction_pointer += 2;
-    }
-    fn bst(&mut self) {
-        self.reg_b = self.combo_op() % 8;
+
+    /// Runs the instruction at the instruction pointer, `None` if the program has halted.
+    pub fn step(&mut self) -> Result<Option<TraceEntry>, Fault> {
+        let address = self.instruction_pointer;
+        let Some(&opcode) = self.instructions.get(address) else {
+            return Ok(None);
+        };
+        let &operand = self
+            .instructions
+            .get(address + 1)
+            .ok_or(Fault::MissingOperand { address })?;
+        let instruction = Instruction::decode(address, opcode, operand)?;
+
+        let before = self.registers();
+        let value = match instruction.operand {
+            Operand::Literal(value) => value,
+            Operand::A => self.reg_a,
+            Operand::B => self.reg_b,
+            Operand::C => self.reg_c,
+            Operand::Ignored => 0,
+        };
+        // shifting every bit out leaves 0, rather than overflowing
+        let shifted = u32::try_from(value)
+            .ok()
+            .and_then(|shift| self.reg_a.checked_shr(shift))
+            .unwrap_or(0);
+        let mut output = None;
         self.instruction_pointer += 2;
-    }
-    fn jnz(&mut self) {
-        if self.reg_a == 0 {
-            self.instruction_pointer += 2;
-        } else {
-            self.instruction_pointer = self.literal_op() as usize;
+        match instruction.opcode {
+            Opcode::Adv => self.reg_a = shifted,
+            Opcode::Bxl => self.reg_b ^= value,
+            Opcode::Bst => self.reg_b = value % 8,
+            Opcode::Jnz => {
+                if self.reg_a != 0 {
+                    self.instruction_pointer = value as usize;
+                }
+            }
+            Opcode::Bxc => self.reg_b ^= self.reg_c,
+            Opcode::Out => {
+                self.output.push(value % 8);
+                output = Some(value % 8);
+            }
+            Opcode::Bdv => self.reg_b = shifted,
+            Opcode::Cdv => self.reg_c = shifted,
         }
+
+        Ok(Some(TraceEntry {
+            instruction,
+            before,
+            after: self.registers(),
+            output,
+        }))
     }
-    fn bxc(&mut self) {
-        self.reg_b ^= self.reg_c;
-        self.instruction_pointer += 2;
+
+    /// Runs until the program halts, giving up after [`DEFAULT_STEP_LIMIT`] instructions.
+    pub fn run(&mut self) -> Result<(), Fault> {
+        self.run_with(&RunOptions::default()).map(|_| ())
     }
-    fn out(&mut self) {
-        self.output.push(self.combo_op() % 8);
-        self.instruction_pointer += 2;
+
+    /// Runs until the program halts or reaches a breakpoint. Breakpoints aren't checked before
+    /// the first instruction, so running again carries on from the one that was reached.
+    pub fn run_with(&mut self, options: &RunOptions) -> Result<Run, Fault> {
+        let mut run = Run {
+            stop: Stop::Halted,
+            steps: 0,
+            trace: vec![],
+        };
+        loop {
+            let address = Breakpoint::Address(self.instruction_pointer);
+            if run.steps > 0 && options.breakpoints.contains(&address) {
+                run.stop = Stop::Breakpoint(address);
+                return Ok(run);
+            }
+            let halted = self.instruction_pointer >= self.instructions.len();
+            if !halted && run.steps == options.step_limit {
+                return Err(Fault::StepLimit { steps: run.steps });
+            }
+
+            let Some(entry) = self.step()? else {
+                return Ok(run);
+            };
+            run.steps += 1;
+            if options.trace {
+                run.trace.push(entry);
+            }
+            let outputs = Breakpoint::Outputs(self.output.len());
+            if entry.output.is_some() && options.breakpoints.contains(&outputs) {
+                run.stop = Stop::Breakpoint(outputs);
+                return Ok(run);
+            }
+        }
     }
-    fn bdv(&mut self) {
-        let num = self.reg_a;
-        let denom = 2_u64.pow(self.combo_op() as u32);
-        self.reg_b = num / denom;
-        self.instruction_pointer += 2;
+
+    /// The program as instructions, with the operands of each resolved.
+    pub fn disassemble(&self) -> Result<Vec<Instruction>> {
+        self.instructions
+            .chunks(2)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let &[opcode, operand] = chunk else {
+                    return Err(Fault::MissingOperand { address: i * 2 }.into());
+                };
+                Ok(Instruction::decode(i * 2, opcode, operand)?)
+            })
+            .collect()
     }
-    fn cdv(&mut self) {
-        let num = self.reg_a;
-        let denom = 2_u64.pow(self.combo_op() as u32);
-        self.reg_c = num / denom;
-        self.instruction_pointer += 2;
+
+    /// The disassembled program, an instruction per line with its address.
+    pub fn listing(&self) -> Result<String> {
+        Ok(self
+            .disassemble()?
+            .iter()
+            .map(|instruction| format!("{:>2}: {instruction}", instruction.address))
+            .join("\n"))
     }
 
-    pub fn run(&mut self) {
-        while let Some(instruction) = self.instructions.get(self.instruction_pointer) {
-            match instruction {
-                0 => self.adv(),
-                1 => self.bxl(),
-                2 => self.bst(),
-                3 => self.jnz(),
-                4 => self.bxc(),
-                5 => self.out(),
-                6 => self.bdv(),
-                7 => self.cdv(),
-                unhandled => panic!("{unhandled:?} is an unknown opcode"),
+    /// Lifts the program into a loop that shifts 3 bits off A, outputs one value, and goes round
+    /// again until A is 0. B and C have to be set from A before they are read, so every value
+    /// output only depends on what is left of A.
+    pub fn decompile(&self) -> Result<Loop> {
+        let mut body = self.disassemble()?;
+        let jump = body.pop().context("the program is empty")?;
+        ensure!(
+            jump.opcode == Opcode::Jnz && jump.operand == Operand::Literal(0),
+            "the program doesn't end by jumping back to the start"
+        );
+
+        let shifts = body
+            .iter()
+            .filter(|i| i.opcode == Opcode::Adv)
+            .collect_vec();
+        ensure!(
+            shifts.len() == 1 && shifts[0].operand == Operand::Literal(3),
+            "the loop doesn't shift A by 3 exactly once"
+        );
+        let outputs = body.iter().filter(|i| i.opcode == Opcode::Out).count();
+        ensure!(outputs == 1, "the loop outputs {outputs} values, not 1");
+
+        let (mut b_set, mut c_set) = (false, false);
+        for instruction in &body {
+            match instruction.opcode {
+                Opcode::Jnz => bail!("the loop jumps from {}", instruction.address),
+                Opcode::Bxl => ensure!(b_set, "B is read at {}", instruction.address),
+                Opcode::Bxc => ensure!(b_set && c_set, "B or C is read at {}", instruction.address),
+                _ => {}
+            }
+            match instruction.operand {
+                Operand::B => ensure!(b_set, "B is read at {}", instruction.address),
+                Operand::C => ensure!(c_set, "C is read at {}", instruction.address),
+                _ => {}
+            }
+            match instruction.opcode {
+                Opcode::Bst | Opcode::Bdv => b_set = true,
+                Opcode::Cdv => c_set = true,
+                _ => {}
             }
         }
+        Ok(Loop { body })
     }
 
-    // work backwards from the last digit, adding 1 repeatedly to increment the first digit
-    // multiply by 8 to lock all correct digits
-    pub fn min_quine(&mut self) -> u64 {
-        // start at 8 and from the 2nd to last digit because the final digit always 0
-        let mut quine = 8;
-        for idx in (0..self.instructions.len() - 1).rev() {
-            loop {
-                self.reset();
-                self.reg_a = quine;
-                self.run();
-
-                if self
-                    .output
-                    .iter()
-                    .zip(self.instructions[idx..].iter())
-                    .all(|(a, b)| a == b)
-                {
-                    if self.output.len() != self.instructions.len() {
-                        quine *= 8;
-                    }
-                    break;
-                }
-                quine += 1;
+    /// The smallest A that makes the program output itself. Each loop outputs a value for what is
+    /// left of A and shifts 3 bits off, so A is built 3 bits at a time, from the bits that are
+    /// output last. The first A found going through the bits in order is the smallest.
+    pub fn min_quine(&mut self) -> Result<u64> {
+        self.decompile()?;
+        let last = self.instructions.len() - 1;
+        self.quine_from(0, last)
+            .context("no value of A makes the program output itself")
+    }
+
+    /// The smallest A, starting with the bits `a`, that outputs the program from `idx` on.
+    fn quine_from(&mut self, a: u64, idx: usize) -> Option<u64> {
+        for bits in 0..8 {
+            let candidate = a * 8 + bits;
+            self.reset();
+            self.reg_a = candidate;
+            if self.run().is_err() || self.output != self.instructions[idx..] {
+                continue;
+            }
+            if idx == 0 {
+                return Some(candidate);
+            }
+            if let Some(quine) = self.quine_from(candidate, idx - 1) {
+                return Some(quine);
             }
         }
-        quine
+        None
     }
 
     fn reset(&mut self) {
@@ -120,25 +221,311 @@ impl Computer {
     }
 }
 
-pub fn parse(input: &str) -> Computer {
-    let (reg_a, reg_b, reg_c, instructions) = input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl TryFrom<u64> for Opcode {
+    type Error = anyhow::Error;
+
+    fn try_from(opcode: u64) -> Result<Self> {
+        Ok(match opcode {
+            0 => Opcode::Adv,
+            1 => Opcode::Bxl,
+            2 => Opcode::Bst,
+            3 => Opcode::Jnz,
+            4 => Opcode::Bxc,
+            5 => Opcode::Out,
+            6 => Opcode::Bdv,
+            7 => Opcode::Cdv,
+            unknown => bail!("{unknown} is an unknown opcode"),
+        })
+    }
+}
+
+impl Display for Opcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mnemonic = match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        };
+        write!(f, "{mnemonic}")
+    }
+}
+
+/// An operand as the instruction reads it. Combo operands 4 to 6 read a register, and `bxc`
+/// ignores its operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Literal(u64),
+    A,
+    B,
+    C,
+    Ignored,
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Literal(value) => write!(f, "{value}"),
+            Operand::A => write!(f, "A"),
+            Operand::B => write!(f, "B"),
+            Operand::C => write!(f, "C"),
+            Operand::Ignored => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub address: usize,
+    pub opcode: Opcode,
+    pub operand: Operand,
+}
+
+impl Instruction {
+    fn decode(address: usize, opcode: u64, operand: u64) -> Result<Self, Fault> {
+        let Ok(opcode) = Opcode::try_from(opcode) else {
+            return Err(Fault::UnknownOpcode { address, opcode });
+        };
+        let operand = match opcode {
+            Opcode::Bxl | Opcode::Jnz => Operand::Literal(operand),
+            Opcode::Bxc => Operand::Ignored,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv => match operand {
+                0..=3 => Operand::Literal(operand),
+                4 => Operand::A,
+                5 => Operand::B,
+                6 => Operand::C,
+                operand => return Err(Fault::InvalidComboOperand { address, operand }),
+            },
+        };
+        Ok(Instruction {
+            address,
+            opcode,
+            operand,
+        })
+    }
+
+    /// The instruction as a statement, `None` for jumps.
+    fn statement(&self) -> Option<String> {
+        let operand = self.operand;
+        Some(match self.opcode {
+            Opcode::Adv => format!("A >>= {operand}"),
+            Opcode::Bxl => format!("B ^= {operand}"),
+            Opcode::Bst => format!("B = {operand} % 8"),
+            Opcode::Jnz => return None,
+            Opcode::Bxc => "B ^= C".to_string(),
+            Opcode::Out => format!("out({operand} % 8)"),
+            Opcode::Bdv => format!("B = A >> {operand}"),
+            Opcode::Cdv => format!("C = A >> {operand}"),
+        })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.operand {
+            Operand::Ignored => write!(f, "{}", self.opcode),
+            operand => write!(f, "{} {operand}", self.opcode),
+        }
+    }
+}
+
+/// A program that runs its body until A is 0, as checked by [`Computer::decompile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loop {
+    pub body: Vec<Instruction>,
+}
+
+impl Display for Loop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "do {{")?;
+        for statement in self.body.iter().filter_map(Instruction::statement) {
+            writeln!(f, "    {statement}")?;
+        }
+        write!(f, "}} while A != 0")
+    }
+}
+
+/// The registers at one point of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "A={} B={} C={}", self.a, self.b, self.c)
+    }
+}
+
+/// An instruction that ran, with the registers before and after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<u64>,
+}
+
+impl Display for TraceEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let instruction = self.instruction.to_string();
+        write!(
+            f,
+            "{:>2}: {instruction:<5} | {} -> {}",
+            self.instruction.address, self.before, self.after
+        )?;
+        if let Some(output) = self.output {
+            write!(f, " | out {output}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Enough for any of the puzzle's programs, which run for a few hundred instructions.
+pub const DEFAULT_STEP_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Before running the instruction at this address.
+    Address(usize),
+    /// After outputting this many values.
+    Outputs(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Fail with [`Fault::StepLimit`] after running this many instructions without halting.
+    pub step_limit: usize,
+    pub breakpoints: Vec<Breakpoint>,
+    /// Record every instruction that runs.
+    pub trace: bool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            step_limit: DEFAULT_STEP_LIMIT,
+            breakpoints: vec![],
+            trace: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    Breakpoint(Breakpoint),
+}
+
+/// How a run went, with the instructions that ran if they were traced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub stop: Stop,
+    pub steps: usize,
+    pub trace: Vec<TraceEntry>,
+}
+
+/// Why a program couldn't carry on running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    UnknownOpcode {
+        address: usize,
+        opcode: u64,
+    },
+    /// Combo operand 7 is reserved.
+    InvalidComboOperand {
+        address: usize,
+        operand: u64,
+    },
+    /// The program ends half way through an instruction.
+    MissingOperand {
+        address: usize,
+    },
+    StepLimit {
+        steps: usize,
+    },
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::UnknownOpcode { address, opcode } => {
+                write!(f, "{opcode} at {address} is an unknown opcode")
+            }
+            Fault::InvalidComboOperand { address, operand } => {
+                write!(f, "{operand} at {address} is not a valid combo operand")
+            }
+            Fault::MissingOperand { address } => {
+                write!(f, "the instruction at {address} has no operand")
+            }
+            Fault::StepLimit { steps } => write!(f, "still running after {steps} steps"),
+        }
+    }
+}
+
+impl Error for Fault {}
+
+pub fn parse(input: &str) -> Result<Computer> {
+    let values = input
         .lines()
         .filter(|l| !l.is_empty())
-        .map(|l| l.split_once(": ").unwrap().1)
-        .collect_tuple()
-        .unwrap();
+        .map(|l| {
+            l.split_once(": ")
+                .map(|(_, value)| value)
+                .with_context(|| format!("{l} isn't a name and a value"))
+        })
+        .collect::<Result<Vec<&str>>>()?;
+    let &[reg_a, reg_b, reg_c, instructions] = values.as_slice() else {
+        bail!("expected 3 registers and a program");
+    };
     let instructions = instructions
         .split(',')
-        .map(|s| s.parse().unwrap())
-        .collect_vec();
-    let output = Vec::new();
-    Computer {
-        reg_a: reg_a.parse().unwrap(),
-        reg_b: reg_b.parse().unwrap(),
-        reg_c: reg_c.parse().unwrap(),
+        .map(|s| s.parse().with_context(|| format!("{s} isn't a number")))
+        .collect::<Result<Vec<u64>>>()?;
+    Ok(Computer {
+        reg_a: reg_a.parse()?,
+        reg_b: reg_b.parse()?,
+        reg_c: reg_c.parse()?,
         instruction_pointer: 0,
         instructions,
-        output,
+        output: vec![],
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Computer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(mut computer: Self::Input<'_>) -> Result<impl Display> {
+        computer.run()?;
+        Ok(computer.output.into_iter().join(","))
+    }
+
+    fn part2(mut computer: Self::Input<'_>) -> Result<impl Display> {
+        computer.min_quine()
     }
 }
 
@@ -160,37 +547,140 @@ Register C: 0
 
 Program: 0,3,5,4,3,0
 ";
-    const INPUT: &str = include_str!("../../input/2024/17.txt");
 
     #[test]
     fn test_1_sample() {
-        let mut input = parse(SAMPLE);
-        input.run();
+        let mut input = parse(SAMPLE).unwrap();
+        input.run().unwrap();
 
         assert_eq!(input.output.into_iter().join(","), "4,6,3,5,6,3,5,2,1,0");
     }
 
     #[test]
-    fn test_1() {
-        let mut input = parse(INPUT);
-        input.run();
+    fn test_2_sample() {
+        let mut computer = parse(SAMPLE_2).unwrap();
+        let quine = computer.min_quine().unwrap();
 
-        assert_eq!(input.output.into_iter().join(","), "2,0,7,3,0,3,1,3,7");
+        assert_eq!(quine, 117_440);
     }
 
     #[test]
-    fn test_2_sample() {
-        let mut computer = parse(SAMPLE_2);
-        let quine = computer.min_quine();
+    fn test_listing() {
+        let computer = parse(SAMPLE_2).unwrap();
 
-        assert_eq!(quine, 117_440);
+        assert_eq!(
+            computer.listing().unwrap(),
+            " 0: adv 3\n 2: out A\n 4: jnz 0"
+        );
+        assert_eq!(
+            computer.decompile().unwrap().to_string(),
+            "do {\n    A >>= 3\n    out(A % 8)\n} while A != 0"
+        );
+    }
+
+    #[test]
+    fn test_decompile() {
+        let program =
+            |instructions: &str| parse(&format!("A: 0\nB: 0\nC: 0\nP: {instructions}")).unwrap();
+
+        let computer = program("2,4,1,1,7,5,4,4,1,4,0,3,5,5,3,0");
+        assert_eq!(
+            computer.decompile().unwrap().to_string(),
+            "\
+do {
+    B = A % 8
+    B ^= 1
+    C = A >> B
+    B ^= C
+    B ^= 4
+    A >>= 3
+    out(B % 8)
+} while A != 0"
+        );
+
+        // B is read before it is set
+        assert!(program("1,1,0,3,5,5,3,0").decompile().is_err());
+        // A shifts by 1
+        assert!(program("0,1,5,4,3,0").decompile().is_err());
+        // combo operand 7
+        assert!(program("5,7,3,0").disassemble().is_err());
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut computer = parse(SAMPLE).unwrap();
+        let options = RunOptions {
+            trace: true,
+            ..RunOptions::default()
+        };
+        let run = computer.run_with(&options).unwrap();
+
+        assert_eq!(run.stop, Stop::Halted);
+        assert_eq!(run.steps, 30);
+        assert_eq!(
+            run.trace[..3].iter().map(ToString::to_string).collect_vec(),
+            [
+                " 0: adv 1 | A=729 B=0 C=0 -> A=364 B=0 C=0",
+                " 2: out A | A=364 B=0 C=0 -> A=364 B=0 C=0 | out 4",
+                " 4: jnz 0 | A=364 B=0 C=0 -> A=364 B=0 C=0",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut computer = parse(SAMPLE).unwrap();
+        let options = RunOptions {
+            breakpoints: vec![Breakpoint::Outputs(3), Breakpoint::Address(0)],
+            ..RunOptions::default()
+        };
+
+        let run = computer.run_with(&options).unwrap();
+        assert_eq!(run.stop, Stop::Breakpoint(Breakpoint::Address(0)));
+        assert_eq!((run.steps, computer.reg_a), (3, 364));
+
+        computer.run_with(&options).unwrap();
+        let run = computer.run_with(&options).unwrap();
+        assert_eq!(run.stop, Stop::Breakpoint(Breakpoint::Outputs(3)));
+        assert_eq!(computer.output, [4, 6, 3]);
+
+        computer.run().unwrap();
+        assert_eq!(computer.output.len(), 10);
     }
 
     #[test]
-    fn test_2() {
-        let mut computer = parse(INPUT);
-        let quine = computer.min_quine();
+    fn test_faults() {
+        let program =
+            |instructions: &str| parse(&format!("A: 1\nB: 0\nC: 0\nP: {instructions}")).unwrap();
 
-        assert_eq!(quine, 247_839_539_763_386);
+        assert_eq!(
+            program("3,0").run(),
+            Err(Fault::StepLimit {
+                steps: DEFAULT_STEP_LIMIT
+            })
+        );
+        assert_eq!(
+            program("1,2,5,7").run(),
+            Err(Fault::InvalidComboOperand {
+                address: 2,
+                operand: 7
+            })
+        );
+        assert_eq!(
+            program("8,0").run(),
+            Err(Fault::UnknownOpcode {
+                address: 0,
+                opcode: 8
+            })
+        );
+        assert_eq!(
+            program("0").run(),
+            Err(Fault::MissingOperand { address: 0 })
+        );
+        // shifting by more than 64 bits
+        let mut computer = program("0,4,5,4");
+        computer.reg_a = 100;
+        computer.run().unwrap();
+        assert_eq!(computer.output, [0]);
     }
 }
@@ -1,66 +1,12 @@
-use std::cmp::Reverse;
-use std::collections::HashMap;
-use std::collections::{BinaryHeap, HashSet};
-use std::ops::Add;
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl PartialOrd for Point {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for Point {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.x.cmp(&other.x) {
-            std::cmp::Ordering::Equal => self.y.cmp(&other.y),
-            other => other,
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Add<Direction> for Point {
-    type Output = Point;
-
-    fn add(self, direction: Direction) -> Self::Output {
-        match direction {
-            Direction::Up => Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Down => Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Left => Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::Right => Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
-    }
-}
+use crate::grid::{Grid, Point};
+use crate::search;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
+use std::fmt::Display;
 
 pub struct MemorySpace {
-    pub width: i32,
-    pub height: i32,
-    pub fallen: HashSet<Point>,
+    /// Whether a byte has fallen onto each point, the exit is the bottom right corner.
+    pub fallen: Grid<bool>,
     pub bytes: Vec<Point>,
 }
 
@@ -78,22 +24,16 @@ impl MemorySpace {
             .collect();
 
         MemorySpace {
-            width,
-            height,
             bytes,
-            fallen: HashSet::new(),
+            fallen: Grid::new(width as usize + 1, height as usize + 1, false),
         }
     }
 
     pub fn fall(&mut self, idx: usize) {
-        self.fallen.clear();
-        self.bytes[..=idx].iter().for_each(|p| {
-            self.fallen.insert(*p);
-        });
-    }
-
-    fn in_bounds(&self, point: Point) -> bool {
-        point.x >= 0 && point.x <= self.width && point.y >= 0 && point.y <= self.height
+        self.fallen = Grid::new(self.fallen.width(), self.fallen.height(), false);
+        for &point in &self.bytes[..=idx] {
+            self.fallen[point] = true;
+        }
     }
 
     pub fn most_fallen(&mut self) -> Option<Point> {
@@ -116,59 +56,50 @@ impl MemorySpace {
     }
 
     pub fn shortest_path(&self) -> Option<i32> {
-        fn manhattan_distance(a: Point, b: Point) -> i32 {
-            (a.x - b.x).abs() + (a.y - b.y).abs()
-        }
-
         let start = Point { x: 0, y: 0 };
         let goal = Point {
-            x: self.width,
-            y: self.height,
+            x: self.fallen.width() as i32 - 1,
+            y: self.fallen.height() as i32 - 1,
         };
 
-        // Directions to move
-        let directions = [
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-        ];
-
-        let mut priority_queue = BinaryHeap::new();
-        priority_queue.push(Reverse((0, 0, start))); // (num_steps + manhattan, num_steps, point)
-
-        let mut min_steps_by_points = HashMap::new();
-        min_steps_by_points.insert(start, 0);
+        search::astar(
+            start,
+            |&current| {
+                current
+                    .neighbors()
+                    .into_iter()
+                    // Ignore points out of bounds or with a fallen byte
+                    .filter(|&neighbor| self.fallen.get(neighbor) == Some(&false))
+                    .map(|neighbor| (neighbor, 1))
+            },
+            |point| point.manhattan(goal),
+            |&point| point == goal,
+        )
+        .map(|path| path.cost)
+    }
+}
 
-        while let Some(Reverse((_, current_steps, current))) = priority_queue.pop() {
-            if current == goal {
-                return Some(current_steps);
-            }
+pub struct Day;
 
-            for &direction in &directions {
-                let neighbor = current + direction;
+impl Solution for Day {
+    type Input<'a> = MemorySpace;
 
-                // Ignore points out of bounds or inaccessible ('#')
-                if !self.in_bounds(neighbor) || self.fallen.contains(&neighbor) {
-                    continue;
-                }
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(MemorySpace::new(70, 70, input))
+    }
 
-                let tentative_next_steps = current_steps + 1;
-                if tentative_next_steps < *min_steps_by_points.get(&neighbor).unwrap_or(&i32::MAX) {
-                    min_steps_by_points.insert(neighbor, tentative_next_steps);
-
-                    let heuristic_with_steps =
-                        tentative_next_steps + manhattan_distance(neighbor, goal);
-                    priority_queue.push(Reverse((
-                        heuristic_with_steps,
-                        tentative_next_steps,
-                        neighbor,
-                    )));
-                }
-            }
-        }
+    fn part1(mut memory_space: Self::Input<'_>) -> Result<impl Display> {
+        memory_space.fall(1_024 - 1);
+        memory_space
+            .shortest_path()
+            .ok_or_else(|| anyhow!("the exit is unreachable"))
+    }
 
-        None // No path found
+    fn part2(mut memory_space: Self::Input<'_>) -> Result<impl Display> {
+        memory_space
+            .most_fallen()
+            .map(|point| format!("{},{}", point.x, point.y))
+            .ok_or_else(|| anyhow!("the exit is never cut off"))
     }
 }
 
@@ -203,7 +134,6 @@ mod tests {
 1,6
 2,0
 ";
-    const INPUT: &str = include_str!("../../input/2024/18.txt");
 
     #[test]
     fn test_1_sample() {
@@ -214,26 +144,10 @@ mod tests {
         assert_eq!(steps, 22);
     }
 
-    #[test]
-    fn test_1() {
-        let mut memory_space = MemorySpace::new(70, 70, INPUT);
-        memory_space.fall(1_024 - 1);
-        let steps = memory_space.shortest_path().unwrap();
-
-        assert_eq!(steps, 250);
-    }
-
     #[test]
     fn test_2_sample() {
         let mut memory_space = MemorySpace::new(6, 6, SAMPLE);
         let point = memory_space.most_fallen().unwrap();
         assert_eq!(point, Point { x: 6, y: 1 });
     }
-
-    #[test]
-    fn test_2() {
-        let mut memory_space = MemorySpace::new(70, 70, INPUT);
-        let point = memory_space.most_fallen().unwrap();
-        assert_eq!(point, Point { x: 56, y: 8 });
-    }
 }
@@ -1,44 +1,14 @@
+use crate::grid::{Grid, Overlay, Point};
+use crate::search;
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use std::collections::HashSet;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Point {
-    pub fn neighbors(&self) -> Vec<Point> {
-        vec![
-            Point {
-                x: self.x,
-                y: self.y - 1,
-            }, // North
-            Point {
-                x: self.x,
-                y: self.y + 1,
-            }, // South
-            Point {
-                x: self.x - 1,
-                y: self.y,
-            }, // West
-            Point {
-                x: self.x + 1,
-                y: self.y,
-            }, // East
-        ]
-    }
-}
+use std::fmt::Display;
 
 pub struct Racetrack {
     pub start: Point,
     pub end: Point,
-    pub height: usize,
-    pub width: usize,
-    pub walls: HashSet<Point>,
-}
-
-fn manhattan(p1: Point, p2: Point) -> usize {
-    (p1.x.abs_diff(p2.x) + p1.y.abs_diff(p2.y)) as usize
+    pub grid: Grid<char>,
 }
 
 impl Racetrack {
@@ -88,7 +58,7 @@ impl Racetrack {
             // - i+1 is the immediate neighbor and would pass through no walls
             // - i+2 and i+3 could be reached through a wall, but would not save time
             for (j, &p2) in path.iter().enumerate().skip(i + 4) {
-                let distance = manhattan(p1, p2);
+                let distance = p1.manhattan(p2) as usize;
                 if distance <= cheat_size {
                     cheats.push((i, j, distance));
                 }
@@ -97,89 +67,54 @@ impl Racetrack {
 
         cheats
     }
-    pub fn print_path(&self, path: &HashSet<Point>) {
-        for y in 0..=self.height as i32 {
-            for x in 0..=self.width as i32 {
-                let point = Point { x, y };
-                if self.start == point {
-                    print!("S");
-                } else if self.end == point {
-                    print!("E");
-                } else if self.walls.contains(&point) {
-                    print!("#");
-                } else if path.contains(&point) {
-                    print!("O");
-                } else {
-                    print!(".");
-                }
-            }
-            println!(); // Move to the next line in the grid
-        }
-    }
-    pub fn in_bounds(&self, point: &Point) -> bool {
-        point.x >= 0
-            && point.y >= 0
-            && point.x <= self.width as i32
-            && point.y <= self.height as i32
+    /// The track with `path` drawn on it as `O`, ready to print.
+    pub fn path_overlay(&self, path: &HashSet<Point>) -> Overlay<'_, char> {
+        self.grid
+            .overlay(path.iter().copied(), 'O')
+            .overlay([self.start], 'S')
+            .overlay([self.end], 'E')
     }
 
-    // simplified floodfill as there is only ever one path with no
+    // there is only ever one path, so the shortest one is the whole track
     pub fn get_track(&self) -> Vec<Point> {
-        let mut track = Vec::new();
-        let mut seen = HashSet::new();
-        track.push(self.start);
-        seen.insert(self.start);
-
-        let mut current = self.start;
-        while let Some(next) = current
-            .neighbors()
-            .into_iter()
-            .find(|p| self.in_bounds(p) && !self.walls.contains(p) && !seen.contains(p))
-        {
-            current = next;
-            track.push(next);
-            seen.insert(next);
-        }
-        track
+        search::bfs(
+            self.start,
+            |point| {
+                point
+                    .neighbors()
+                    .into_iter()
+                    .filter(|&p| self.grid.get(p).is_some_and(|&c| c != '#'))
+            },
+            |&point| point == self.end,
+        )
+        .map(|path| path.states)
+        .expect("the track should lead from the start to the end")
     }
 }
 
-pub fn parse(input: &str) -> Racetrack {
-    let mut walls = HashSet::new();
-    let mut start = None;
-    let mut end = None;
-    let mut height = 0;
-    let mut width = 0;
-
-    for (y, line) in input.lines().enumerate() {
-        height = height.max(y);
-        for (x, c) in line.chars().enumerate() {
-            width = width.max(x);
-            let point = Point {
-                x: x as i32,
-                y: y as i32,
-            };
-            match c {
-                '#' => {
-                    walls.insert(point);
-                }
-                'S' => {
-                    start = Some(point);
-                }
-                'E' => {
-                    end = Some(point);
-                }
-                _ => {}
-            }
-        }
+pub fn parse(input: &str) -> Result<Racetrack> {
+    let grid: Grid<char> = input.parse()?;
+    let start = grid.find(&'S').ok_or_else(|| anyhow!("No start point"))?;
+    let end = grid.find(&'E').ok_or_else(|| anyhow!("No end point"))?;
+
+    Ok(Racetrack { start, end, grid })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Racetrack;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
     }
 
-    Racetrack {
-        start: start.expect("No start point"),
-        end: end.expect("No end point"),
-        height,
-        width,
-        walls,
+    fn part1(mut racetrack: Self::Input<'_>) -> Result<impl Display> {
+        Ok(racetrack.solve(2, 100))
+    }
+
+    fn part2(mut racetrack: Self::Input<'_>) -> Result<impl Display> {
+        Ok(racetrack.solve(20, 100))
     }
 }
 
@@ -204,33 +139,30 @@ mod tests {
 #...#...#...###
 ###############
 ";
-    const INPUT: &str = include_str!("../../input/2024/20.txt");
 
     #[test]
     fn test_1_sample() {
-        let mut racetrack = parse(SAMPLE);
+        let mut racetrack = parse(SAMPLE).unwrap();
 
         assert_eq!(racetrack.solve(2, 2), 44);
     }
 
-    #[test]
-    fn test_1() {
-        let mut racetrack = parse(INPUT);
-
-        assert_eq!(racetrack.solve(2, 100), 1_399);
-    }
-
     #[test]
     fn test_2_sample() {
-        let mut racetrack = parse(SAMPLE);
+        let mut racetrack = parse(SAMPLE).unwrap();
 
         assert_eq!(racetrack.solve(20, 50), 285);
     }
 
     #[test]
-    fn test_2() {
-        let mut racetrack = parse(INPUT);
-
-        assert_eq!(racetrack.solve(20, 100), 994_807);
+    fn test_path_overlay() {
+        let racetrack = parse(SAMPLE).unwrap();
+        let track = racetrack.get_track().into_iter().collect();
+
+        // the track covers every open cell
+        assert_eq!(
+            racetrack.path_overlay(&track).to_string().trim_end(),
+            SAMPLE.replace('.', "O").trim_end()
+        );
     }
 }
@@ -1,5 +1,8 @@
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
 use std::collections::HashMap;
+use std::fmt::Display;
 use std::iter::once;
 
 // a hardcoded list of all 141 possible permutations of input
@@ -94,6 +97,24 @@ pub fn sum_complexity(input: &str, num_robots: usize) -> usize {
         .sum()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(codes: Self::Input<'_>) -> Result<impl Display> {
+        Ok(sum_complexity(codes, 2))
+    }
+
+    fn part2(codes: Self::Input<'_>) -> Result<impl Display> {
+        Ok(sum_complexity(codes, 25))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,7 +126,6 @@ mod tests {
 456A
 379A
 ";
-    const INPUT: &str = include_str!("../../input/2024/21.txt");
 
     #[test]
     fn test_1_sample() {
@@ -113,18 +133,4 @@ mod tests {
 
         assert_eq!(complexity, 126_384);
     }
-
-    #[test]
-    fn test_1() {
-        let complexity = sum_complexity(INPUT, 2);
-
-        assert_eq!(complexity, 136_780);
-    }
-
-    #[test]
-    fn test_2() {
-        let complexity = sum_complexity(INPUT, 25);
-
-        assert_eq!(complexity, 167_538_833_832_712);
-    }
 }
@@ -1,5 +1,8 @@
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
 use std::collections::HashSet;
+use std::fmt::Display;
 use std::iter::successors;
 
 fn mix(a: i64, b: i64) -> i64 {
@@ -78,6 +81,24 @@ pub fn solve(input: &str) -> (i64, u16) {
     (final_price_sum, *buckets.iter().max().unwrap())
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(secrets: Self::Input<'_>) -> Result<impl Display> {
+        Ok(solve(secrets).0)
+    }
+
+    fn part2(secrets: Self::Input<'_>) -> Result<impl Display> {
+        Ok(solve(secrets).1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,7 +116,6 @@ mod tests {
 3
 2024
 ";
-    const INPUT: &str = include_str!("../../input/2024/22.txt");
 
     #[test]
     fn test_1_sample() {
@@ -104,24 +124,10 @@ mod tests {
         assert_eq!(input.0, 37_327_623);
     }
 
-    #[test]
-    fn test_1() {
-        let input = solve(INPUT);
-
-        assert_eq!(input.0, 14_392_541_715);
-    }
-
     #[test]
     fn test_2_sample() {
         let input = solve(SAMPLE_2);
 
         assert_eq!(input.1, 23);
     }
-
-    #[test]
-    fn test_2() {
-        let input = solve(INPUT);
-
-        assert_eq!(input.1, 1_628);
-    }
 }
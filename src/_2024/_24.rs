@@ -1,189 +1,586 @@
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GateKind {
+    And,
+    Or,
+    Xor,
+}
 
-#[derive(Debug, Clone)]
+impl GateKind {
+    pub fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            GateKind::And => a && b,
+            GateKind::Or => a || b,
+            GateKind::Xor => a != b,
+        }
+    }
+}
+
+impl FromStr for GateKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "AND" => GateKind::And,
+            "OR" => GateKind::Or,
+            "XOR" => GateKind::Xor,
+            unknown => bail!("unknown gate {unknown}"),
+        })
+    }
+}
+
+impl Display for GateKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            GateKind::And => "AND",
+            GateKind::Or => "OR",
+            GateKind::Xor => "XOR",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A gate between wires, by their ids in the [`Netlist`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Gate {
-    pub input_a: String,
-    pub input_b: String,
-    pub output: String,
-    pub operation_str: String,
-    pub operation: fn(bool, bool) -> bool,
+    pub kind: GateKind,
+    pub inputs: [usize; 2],
+    pub output: usize,
 }
 
-pub struct Machine {
-    pub input_queue: Vec<String>,
-    pub input_wires_to_gates: HashMap<String, Vec<Gate>>,
-    pub wires_to_values: HashMap<String, bool>,
-    pub gates: Vec<Gate>,
-    pub swaps: HashMap<String, String>,
+/// A circuit of gates, with the wire names interned into dense ids. The gates are kept in an
+/// order where every gate comes after the gates driving its inputs, so a single pass evaluates
+/// them all. Wires named by a letter and a bit number, like `x00`, make up the buses.
+#[derive(Debug, Clone)]
+pub struct Netlist {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    gates: Vec<Gate>,
+    /// The gate driving each wire, if any.
+    drivers: Vec<Option<usize>>,
+    order: Vec<usize>,
+    buses: HashMap<char, Vec<usize>>,
+    /// The values the input starts the wires with.
+    initial: Vec<(usize, bool)>,
 }
 
-impl Machine {
+impl Netlist {
+    pub fn wire(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, wire: usize) -> &str {
+        &self.names[wire]
+    }
+
+    pub fn wires(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// The index of the gate driving `wire`, `None` for inputs.
+    pub fn driver(&self, wire: usize) -> Option<usize> {
+        self.drivers[wire]
+    }
+
+    /// The wires of a bus, least significant bit first. Empty if there is no such bus.
+    pub fn bus(&self, prefix: char) -> &[usize] {
+        self.buses.get(&prefix).map_or(&[], Vec::as_slice)
+    }
+
+    /// Swaps which wires two gates drive, given the wires.
+    ///
+    /// # Errors
+    ///
+    /// If either wire isn't driven by a gate, or if the swap makes a loop, in which case the
+    /// netlist is left as it was.
+    pub fn swap_outputs(&mut self, a: usize, b: usize) -> Result<()> {
+        let driver = |wire: usize| {
+            self.drivers[wire]
+                .with_context(|| format!("{} isn't driven by a gate", self.names[wire]))
+        };
+        let (gate_a, gate_b) = (driver(a)?, driver(b)?);
+        self.swap_drivers(gate_a, gate_b);
+        match evaluation_order(&self.names, &self.gates, &self.drivers) {
+            Ok(order) => {
+                self.order = order;
+                Ok(())
+            }
+            Err(err) => {
+                self.swap_drivers(gate_a, gate_b);
+                Err(err)
+            }
+        }
+    }
+
+    fn swap_drivers(&mut self, gate_a: usize, gate_b: usize) {
+        let (a, b) = (self.gates[gate_a].output, self.gates[gate_b].output);
+        self.gates[gate_a].output = b;
+        self.gates[gate_b].output = a;
+        self.drivers[a] = Some(gate_b);
+        self.drivers[b] = Some(gate_a);
+    }
+
+    /// Sets every wire a gate drives, from the wires already set.
+    ///
+    /// # Errors
+    ///
+    /// If a gate reads a wire that nothing sets.
+    pub fn evaluate(&self, values: &mut [Option<bool>]) -> Result<()> {
+        for &gate in &self.order {
+            let Gate {
+                kind,
+                inputs: [a, b],
+                output,
+            } = self.gates[gate];
+            let value = |wire: usize| {
+                values[wire].with_context(|| format!("{} has no value", self.names[wire]))
+            };
+            values[output] = Some(kind.apply(value(a)?, value(b)?));
+        }
+        Ok(())
+    }
+
+    pub fn read_bus(&self, values: &[Option<bool>], prefix: char) -> Result<u64> {
+        let bus = self.bus(prefix);
+        ensure!(bus.len() <= 64, "the {prefix} bus is wider than 64 bits");
+        bus.iter().enumerate().try_fold(0, |number, (bit, &wire)| {
+            let value =
+                values[wire].with_context(|| format!("{} has no value", self.names[wire]))?;
+            Ok(number | u64::from(value) << bit)
+        })
+    }
+
+    /// Sets the wires of a bus to the bits of `number`, ignoring the bits the bus doesn't have.
+    pub fn write_bus(&self, values: &mut [Option<bool>], prefix: char, number: u64) {
+        for (bit, &wire) in self.bus(prefix).iter().enumerate().take(64) {
+            values[wire] = Some(number >> bit & 1 == 1);
+        }
+    }
+
+    /// The `z` bus, with the wires set as the input sets them.
+    pub fn run(&self) -> Result<u64> {
+        let mut values = vec![None; self.wires()];
+        for &(wire, value) in &self.initial {
+            values[wire] = Some(value);
+        }
+        self.evaluate(&mut values)?;
+        self.read_bus(&values, 'z')
+    }
+
+    /// The `z` bus, with the `x` and `y` buses set to `x` and `y`.
+    pub fn simulate(&self, x: u64, y: u64) -> Result<u64> {
+        let mut values = vec![None; self.wires()];
+        self.write_bus(&mut values, 'x', x);
+        self.write_bus(&mut values, 'y', y);
+        self.evaluate(&mut values)?;
+        self.read_bus(&values, 'z')
+    }
+
+    /// Tries every way of swapping `number_of_pairs` pairs of `z` outputs, until the circuit
+    /// computes `operation` for every `x` and `y` that fit in their buses. Only feasible for
+    /// small circuits, [`Netlist::analyze_adder`] handles adders of any size.
     pub fn find_swaps(
-        &mut self,
+        &self,
         number_of_pairs: usize,
-        operation: fn(usize, usize) -> usize,
-        number_of_bits: usize,
-    ) -> String {
-        let outputs = self
-            .gates
-            .iter()
-            .map(|gate| gate.output.clone())
-            .filter(|output| output.starts_with('z'))
-            .collect_vec();
+        operation: fn(u64, u64) -> u64,
+    ) -> Result<String> {
+        let outputs = self.bus('z');
+        let (x_bits, y_bits) = (self.bus('x').len(), self.bus('y').len());
+        ensure!(x_bits + y_bits <= 32, "too many input bits to try them all");
 
-        let pairs: Vec<(String, String)> = outputs
+        let swaps = outputs
             .iter()
-            .combinations(2)
-            .map(|v| (v[0].clone(), v[1].clone()))
-            .collect_vec();
-
-        let combs = pairs.iter().combinations(number_of_pairs).filter(|c| {
-            let mut set = HashSet::new();
-            for (a, b) in c {
-                if !set.insert(a) || !set.insert(b) {
-                    return false;
-                }
+            .copied()
+            .tuple_combinations()
+            .combinations(number_of_pairs)
+            .filter(|pairs| pairs.iter().flat_map(|&(a, b)| [a, b]).all_unique());
+        for pairs in swaps {
+            let mut netlist = self.clone();
+            if pairs
+                .iter()
+                .any(|&(a, b)| netlist.swap_outputs(a, b).is_err())
+            {
+                continue;
             }
-            true
-        });
-
-        'swap: for swap in combs {
-            for x in 0..(1 << number_of_bits) {
-                for y in 0..(1 << number_of_bits) {
-                    let target = operation(x, y);
-                    self.reset(x, y, number_of_bits);
-                    self.swaps = swap
-                        .iter()
-                        .flat_map(|(a, b)| vec![(a.clone(), b.clone()), (b.clone(), a.clone())])
-                        .collect();
-                    self.run();
-
-                    if target != self.number('z') {
-                        continue 'swap;
-                    }
-                }
+            let correct = (0..1 << x_bits)
+                .cartesian_product(0..1 << y_bits)
+                .all(|(x, y)| netlist.simulate(x, y).ok() == Some(operation(x, y)));
+            if correct {
+                return Ok(pairs
+                    .iter()
+                    .flat_map(|&(a, b)| [self.name(a), self.name(b)])
+                    .sorted()
+                    .join(","));
             }
+        }
+        bail!("no swaps of {number_of_pairs} pairs of outputs work")
+    }
+}
 
-            return swap.iter().flat_map(|(a, b)| vec![a, b]).sorted().join(",");
+/// Orders the gates so that each comes after the gates driving its inputs, with Kahn's algorithm.
+fn evaluation_order(
+    names: &[String],
+    gates: &[Gate],
+    drivers: &[Option<usize>],
+) -> Result<Vec<usize>> {
+    let mut readers = vec![vec![]; gates.len()];
+    let mut waiting_on = vec![0; gates.len()];
+    for (gate, Gate { inputs, .. }) in gates.iter().enumerate() {
+        for driver in inputs.iter().filter_map(|&wire| drivers[wire]) {
+            readers[driver].push(gate);
+            waiting_on[gate] += 1;
         }
-        panic!("no solution found");
-    }
-    pub fn reset(&mut self, new_x: usize, new_y: usize, bits: usize) {
-        // x and y are never any outputs. This safely clears all outputs while retaining the original
-        // input
-        // self.wires_to_values
-        //     .retain(|key, _| key.starts_with('x') || key.starts_with('y'));
-        self.wires_to_values.clear();
-        for i in 0..bits {
-            self.wires_to_values
-                .insert(format!("x{i:02}"), new_x & (1 << i) != 0);
-            self.wires_to_values
-                .insert(format!("y{i:02}"), new_y & (1 << i) != 0);
+    }
+
+    let mut ready: VecDeque<usize> = (0..gates.len()).filter(|&g| waiting_on[g] == 0).collect();
+    let mut order = Vec::with_capacity(gates.len());
+    while let Some(gate) = ready.pop_front() {
+        order.push(gate);
+        for &reader in &readers[gate] {
+            waiting_on[reader] -= 1;
+            if waiting_on[reader] == 0 {
+                ready.push_back(reader);
+            }
         }
+    }
+
+    if let Some(gate) = (0..gates.len()).find(|&g| waiting_on[g] != 0) {
+        bail!("{} is part of a loop", names[gates[gate].output]);
+    }
+    Ok(order)
+}
 
-        // clear the temporary output overrides for p2
-        self.swaps.clear();
+/// A gate that doesn't fit the ripple-carry adder, on the bit it breaks, with the two wires whose
+/// gates need their outputs swapped to fix it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anomaly {
+    pub bit: usize,
+    pub swap: (String, String),
+    pub reason: String,
+}
 
-        // technically not necessary since run() fully drains input_queue
-        self.input_queue.clear();
-        // add all of x and y to the input queue
-        self.input_queue
-            .extend(self.wires_to_values.keys().cloned());
+impl Display for Anomaly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (a, b) = &self.swap;
+        write!(f, "bit {}: {}, swap {a} and {b}", self.bit, self.reason)
     }
-    pub fn run(&mut self) {
-        while let Some(wire) = self.input_queue.pop() {
-            let gates = self.input_wires_to_gates.get(&wire);
-            if gates.is_none() {
-                continue;
-            }
-            for gate in gates.unwrap() {
-                let a = self.wires_to_values.get(&gate.input_a);
-                let b = self.wires_to_values.get(&gate.input_b);
-                match (a, b) {
-                    (Some(&a), Some(&b)) => {
-                        let op = gate.operation;
-                        let output = op(a, b);
-
-                        // for pt 2, conditionally swap the outputs
-                        let output_wire = self.swaps.get(&gate.output).unwrap_or(&gate.output);
-
-                        self.wires_to_values.insert(output_wire.to_string(), output);
-                        self.input_queue.push(output_wire.clone());
+}
+
+impl Netlist {
+    /// Checks the gates against a ripple-carry adder of `x` and `y` into `z`, one bit at a time.
+    /// Bit 0 is a half adder: `z00 = x00 XOR y00`, with the carry `x00 AND y00`. Every other bit
+    /// is a full adder:
+    ///
+    /// * `sum = x XOR y` and `generate = x AND y`
+    /// * `z = sum XOR carry` and `propagate = sum AND carry`
+    /// * `carry = generate OR propagate`, into the next bit or the last `z`
+    ///
+    /// Whenever a bit doesn't fit, the swap of outputs that makes it fit is recorded and the bit is
+    /// checked again with it applied.
+    ///
+    /// # Errors
+    ///
+    /// If a bit can't be fixed by swapping outputs, like when a gate is missing altogether.
+    pub fn analyze_adder(&self) -> Result<Vec<Anomaly>> {
+        let mut adder = Adder {
+            netlist: self.clone(),
+            anomalies: vec![],
+        };
+        let bits = self.bus('x').len();
+
+        let mut carry = None;
+        for bit in 0..bits {
+            // every swap fixes a gate, so a bit that keeps needing them is beyond repair
+            for attempt in 0.. {
+                if attempt == 4 {
+                    bail!("bit {bit} doesn't look like an adder");
+                }
+                match adder.check_bit(bit, carry)? {
+                    Ok(next_carry) => {
+                        carry = Some(next_carry);
+                        break;
                     }
-                    _ => continue,
-                };
+                    Err(anomaly) => adder.swap(anomaly)?,
+                }
             }
         }
+
+        let carry = carry.context("there are no input bits")?;
+        let carry_wire = adder.netlist.gates[carry].output;
+        let last = adder.wire(&format!("z{bits:02}"))?;
+        if carry_wire != last {
+            let reason = format!("the last carry isn't {}", self.name(last));
+            let anomaly = adder.anomaly(bits, carry_wire, last, reason);
+            adder.swap(anomaly)?;
+        }
+        Ok(adder.anomalies)
+    }
+}
+
+/// A copy of a [`Netlist`] being fixed into an adder.
+struct Adder {
+    netlist: Netlist,
+    anomalies: Vec<Anomaly>,
+}
+
+impl Adder {
+    fn wire(&self, name: &str) -> Result<usize> {
+        self.netlist
+            .wire(name)
+            .ok_or_else(|| anyhow!("there is no {name} wire"))
+    }
+
+    fn name(&self, wire: usize) -> &str {
+        self.netlist.name(wire)
+    }
+
+    fn output(&self, gate: usize) -> usize {
+        self.netlist.gates[gate].output
     }
 
-    pub fn number(&self, starts_with: char) -> usize {
-        self.wires_to_values
+    /// The gate of `kind` with inputs `a` and `b`, in either order.
+    fn find(&self, kind: GateKind, a: usize, b: usize) -> Option<usize> {
+        self.netlist
+            .gates
             .iter()
-            .filter(|(wire, _)| wire.starts_with(starts_with))
-            .collect_vec()
+            .position(|gate| gate.kind == kind && (gate.inputs == [a, b] || gate.inputs == [b, a]))
+    }
+
+    fn find_or_fail(&self, kind: GateKind, a: usize, b: usize) -> Result<usize> {
+        self.find(kind, a, b)
+            .with_context(|| format!("{} {kind} {} is missing", self.name(a), self.name(b)))
+    }
+
+    /// The other input of a gate of `kind` that reads `wire`.
+    fn other_input(&self, kind: GateKind, wire: usize) -> Option<usize> {
+        self.netlist
+            .gates
             .iter()
-            .sorted_by_key(|(wire, _)| wire)
-            .rev()
-            .map(|(_, value)| usize::from(**value))
-            .fold(0, |acc, x| (acc << 1) | x)
+            .filter(|gate| gate.kind == kind)
+            .find_map(|&Gate { inputs: [a, b], .. }| {
+                (a == wire).then_some(b).or((b == wire).then_some(a))
+            })
+    }
+
+    fn anomaly(&self, bit: usize, a: usize, b: usize, reason: String) -> Anomaly {
+        Anomaly {
+            bit,
+            swap: (self.name(a).to_string(), self.name(b).to_string()),
+            reason,
+        }
+    }
+
+    fn swap(&mut self, anomaly: Anomaly) -> Result<()> {
+        let (a, b) = (self.wire(&anomaly.swap.0)?, self.wire(&anomaly.swap.1)?);
+        self.netlist
+            .swap_outputs(a, b)
+            .with_context(|| anomaly.to_string())?;
+        self.anomalies.push(anomaly);
+        Ok(())
+    }
+
+    /// The gate producing the carry out of `bit`, or the first thing wrong with it.
+    fn check_bit(&self, bit: usize, carry: Option<usize>) -> Result<Result<usize, Anomaly>> {
+        let x = self.wire(&format!("x{bit:02}"))?;
+        let y = self.wire(&format!("y{bit:02}"))?;
+        let z = self.wire(&format!("z{bit:02}"))?;
+        let sum = self.find_or_fail(GateKind::Xor, x, y)?;
+        let generate = self.find_or_fail(GateKind::And, x, y)?;
+
+        let Some(carry) = carry else {
+            if self.output(sum) != z {
+                let reason = format!(
+                    "{} XOR {} isn't {}",
+                    self.name(x),
+                    self.name(y),
+                    self.name(z)
+                );
+                return Ok(Err(self.anomaly(bit, self.output(sum), z, reason)));
+            }
+            return Ok(Ok(generate));
+        };
+
+        let (sum_wire, carry_wire) = (self.output(sum), self.output(carry));
+        let (sum_name, carry_name) = (self.name(sum_wire), self.name(carry_wire));
+        let Some(output) = self.find(GateKind::Xor, sum_wire, carry_wire) else {
+            // one of the inputs is right, and the other one has to be swapped in
+            if let Some(wire) = self.other_input(GateKind::Xor, carry_wire) {
+                let reason = format!(
+                    "the sum {} XOR {} is {sum_name}, but {} is used",
+                    self.name(x),
+                    self.name(y),
+                    self.name(wire)
+                );
+                return self.swapped_in(bit, sum_wire, wire, reason);
+            }
+            if let Some(wire) = self.other_input(GateKind::Xor, sum_wire) {
+                let reason = format!(
+                    "the carry in is {carry_name}, but {} is used",
+                    self.name(wire)
+                );
+                return self.swapped_in(bit, carry_wire, wire, reason);
+            }
+            bail!("no XOR gate reads {sum_name} or {carry_name}");
+        };
+        if self.output(output) != z {
+            let reason = format!("{sum_name} XOR {carry_name} isn't {}", self.name(z));
+            return Ok(Err(self.anomaly(bit, self.output(output), z, reason)));
+        }
+
+        let propagate = self.find_or_fail(GateKind::And, sum_wire, carry_wire)?;
+        let (generate_wire, propagate_wire) = (self.output(generate), self.output(propagate));
+        let Some(carry_out) = self.find(GateKind::Or, generate_wire, propagate_wire) else {
+            if let Some(wire) = self.other_input(GateKind::Or, generate_wire) {
+                let reason = format!(
+                    "{sum_name} AND {carry_name} is {}, but {} is used",
+                    self.name(propagate_wire),
+                    self.name(wire)
+                );
+                return self.swapped_in(bit, propagate_wire, wire, reason);
+            }
+            if let Some(wire) = self.other_input(GateKind::Or, propagate_wire) {
+                let reason = format!(
+                    "{} AND {} is {}, but {} is used",
+                    self.name(x),
+                    self.name(y),
+                    self.name(generate_wire),
+                    self.name(wire)
+                );
+                return self.swapped_in(bit, generate_wire, wire, reason);
+            }
+            bail!(
+                "no OR gate reads {} or {}",
+                self.name(generate_wire),
+                self.name(propagate_wire)
+            );
+        };
+        Ok(Ok(carry_out))
+    }
+
+    fn swapped_in(
+        &self,
+        bit: usize,
+        wire: usize,
+        used: usize,
+        reason: String,
+    ) -> Result<Result<usize, Anomaly>> {
+        if self.netlist.driver(used).is_none() {
+            bail!("bit {bit}: {reason}, which no gate outputs");
+        }
+        Ok(Err(self.anomaly(bit, wire, used, reason)))
     }
 }
 
-pub fn parse(input: &str) -> Machine {
-    let (input_queue, gates) = input.split_once("\n\n").unwrap();
-    let wires_to_values: HashMap<String, bool> = input_queue
+pub fn parse(input: &str) -> Result<Netlist> {
+    let (initial, gate_lines) = input
+        .split_once("\n\n")
+        .context("the input has no empty line between the wires and the gates")?;
+
+    let mut names: Vec<String> = vec![];
+    let mut ids: HashMap<String, usize> = HashMap::new();
+    let mut intern = |name: &str| {
+        *ids.entry(name.to_string()).or_insert_with(|| {
+            names.push(name.to_string());
+            names.len() - 1
+        })
+    };
+
+    let initial = initial
         .lines()
         .map(|line| {
-            let (input, value) = line.split_once(": ").unwrap();
-            (input.to_string(), value == "1")
+            let (wire, value) = line
+                .split_once(": ")
+                .with_context(|| format!("{line} isn't a wire and its value"))?;
+            Ok((intern(wire), value == "1"))
         })
-        .collect();
-    let input_queue = wires_to_values.keys().cloned().collect();
-    let gates = gates
+        .collect::<Result<Vec<_>>>()?;
+    let gates = gate_lines
         .lines()
         .map(|line| {
-            let (input_a, operation_str, input_b, _, output) =
-                line.split_whitespace().collect_tuple().unwrap();
-            let operation = match operation_str {
-                "AND" => |a, b| a && b,
-                "XOR" => |a, b| a != b,
-                "OR" => |a, b| a || b,
-                operation => panic!("unhandled operation {operation}"),
+            let Some((a, kind, b, "->", output)) = line.split_whitespace().collect_tuple() else {
+                bail!("{line} isn't a gate");
             };
-            Gate {
-                input_a: input_a.to_string(),
-                input_b: input_b.to_string(),
-                output: output.to_string(),
-                operation,
-                operation_str: operation_str.to_string(),
-            }
+            Ok(Gate {
+                kind: kind.parse()?,
+                inputs: [intern(a), intern(b)],
+                output: intern(output),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut drivers = vec![None; names.len()];
+    for (index, gate) in gates.iter().enumerate() {
+        if drivers[gate.output].replace(index).is_some() {
+            bail!("{} is driven by more than one gate", names[gate.output]);
+        }
+    }
+    let order = evaluation_order(&names, &gates, &drivers)?;
+
+    let mut buses: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
+    for (wire, name) in names.iter().enumerate() {
+        let mut chars = name.chars();
+        if let (Some(prefix), Ok(bit)) = (chars.next(), chars.as_str().parse::<usize>()) {
+            buses.entry(prefix).or_default().push((bit, wire));
+        }
+    }
+    let buses = buses
+        .into_iter()
+        .map(|(prefix, bits)| {
+            (
+                prefix,
+                bits.into_iter().sorted().map(|(_, wire)| wire).collect(),
+            )
         })
-        .collect_vec();
-    let mut input_wires_to_gates = HashMap::new();
-    for gate in &gates {
-        input_wires_to_gates
-            .entry(gate.input_a.clone())
-            .or_insert_with(Vec::new)
-            .push(gate.clone());
-        input_wires_to_gates
-            .entry(gate.input_b.clone())
-            .or_insert_with(Vec::new)
-            .push(gate.clone());
-    }
-    Machine {
-        input_queue,
-        input_wires_to_gates,
-        wires_to_values,
+        .collect();
+
+    Ok(Netlist {
+        names,
+        ids,
         gates,
-        swaps: HashMap::new(),
+        drivers,
+        order,
+        buses,
+        initial,
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Netlist;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(netlist: Self::Input<'_>) -> Result<impl Display> {
+        netlist.run()
+    }
+
+    fn part2(netlist: Self::Input<'_>) -> Result<impl Display> {
+        Ok(netlist
+            .analyze_adder()?
+            .into_iter()
+            .flat_map(|anomaly| [anomaly.swap.0, anomaly.swap.1])
+            .sorted()
+            .join(","))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rand::{thread_rng, Rng};
     use std::ops::BitAnd;
 
     const SAMPLE: &str = "\
@@ -271,96 +668,123 @@ x04 AND y04 -> z04
 x05 AND y05 -> z00
 ";
 
-    const INPUT: &str = include_str!("../../input/2024/24.txt");
-
     #[test]
     fn test_1_sample() {
-        let mut machine = parse(SAMPLE);
-        machine.run();
+        let netlist = parse(SAMPLE).unwrap();
 
-        assert_eq!(machine.number('z'), 4);
+        assert_eq!(netlist.run().unwrap(), 4);
     }
 
     #[test]
     fn test_1_sample_2() {
-        let mut machine = parse(SAMPLE_2);
+        let netlist = parse(SAMPLE_2).unwrap();
 
-        machine.run();
+        assert_eq!(netlist.run().unwrap(), 2024);
+    }
 
-        assert_eq!(machine.number('z'), 2024);
+    /// A ripple-carry adder of `bits` bits, with the outputs of each pair of wires swapped.
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> String {
+        let mut input = (0..bits)
+            .flat_map(|i| [format!("x{i:02}: 0"), format!("y{i:02}: 0")])
+            .join("\n");
+        input.push_str("\n\n");
+        let mut gates = vec![
+            "x00 XOR y00 -> z00".to_string(),
+            "x00 AND y00 -> c00".to_string(),
+        ];
+        for i in 1..bits {
+            let carry_out = if i == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            gates.extend([
+                format!("x{i:02} XOR y{i:02} -> s{i:02}"),
+                format!("y{i:02} AND x{i:02} -> g{i:02}"),
+                format!("c{:02} XOR s{i:02} -> z{i:02}", i - 1),
+                format!("s{i:02} AND c{:02} -> p{i:02}", i - 1),
+                format!("g{i:02} OR p{i:02} -> {carry_out}"),
+            ]);
+        }
+        let gates = gates
+            .iter()
+            .map(|gate| {
+                let (operation, output) = gate.split_once(" -> ").unwrap();
+                let output = swaps
+                    .iter()
+                    .find_map(|&(a, b)| (output == a).then_some(b).or((output == b).then_some(a)))
+                    .unwrap_or(output);
+                format!("{operation} -> {output}")
+            })
+            .join("\n");
+        input + &gates
     }
 
     #[test]
-    fn test_1() {
-        let mut machine = parse(INPUT);
-        machine.run();
+    fn test_adder() {
+        let netlist = parse(&adder(8, &[])).unwrap();
 
-        assert_eq!(machine.number('z'), 64_755_511_006_320);
+        assert_eq!(netlist.analyze_adder().unwrap(), []);
+        assert_eq!(netlist.simulate(173, 94).unwrap(), 173 + 94);
+        assert_eq!(netlist.bus('z').len(), 9);
     }
 
     #[test]
-    fn test_2_sample() {
-        let mut machine = parse(SAMPLE_3);
-        let swaps = machine.find_swaps(2, usize::bitand, 6);
-
-        assert_eq!(swaps, "z00,z01,z02,z05");
+    fn test_swap_outputs() {
+        let mut netlist = parse(&adder(4, &[])).unwrap();
+        let wire = |name| netlist.wire(name).unwrap();
+        let (x00, z01, z02) = (wire("x00"), wire("z01"), wire("z02"));
+        let (c01, g02) = (wire("c01"), wire("g02"));
+
+        // c01 XOR s02 -> z02 would read its own output
+        let err = netlist.swap_outputs(z02, c01).unwrap_err();
+        assert_eq!(err.to_string(), "c01 is part of a loop");
+        assert_eq!(netlist.simulate(5, 6).unwrap(), 11);
+        assert!(netlist.swap_outputs(x00, z01).is_err());
+
+        netlist.swap_outputs(z02, g02).unwrap();
+        assert_ne!(netlist.simulate(5, 6).unwrap(), 11);
+        netlist.swap_outputs(z02, g02).unwrap();
+        assert_eq!(netlist.simulate(5, 6).unwrap(), 11);
     }
 
     #[test]
-    fn test_2() {
-        let mut machine = parse(INPUT);
-        let mut swaps = HashMap::new();
-        swaps.insert("sbg".to_string(), "z19".to_string());
-        swaps.insert("z19".to_string(), "sbg".to_string());
-        swaps.insert("djg".to_string(), "z12".to_string());
-        swaps.insert("z12".to_string(), "djg".to_string());
-        swaps.insert("dsd".to_string(), "z37".to_string());
-        swaps.insert("z37".to_string(), "dsd".to_string());
-        swaps.insert("hjm".to_string(), "mcq".to_string());
-        swaps.insert("mcq".to_string(), "hjm".to_string());
-
-        machine.reset(1, 1, 45);
-        machine.swaps = swaps.clone();
-
-        // 1. Edge Cases
-        let test_cases = vec![
-            (0, 0),                                   // Both inputs are zero
-            (0, (1usize << 44) - 1),                  // x = 0, y = max 44-bit value
-            ((1usize << 44) - 1, 0),                  // x = max 44-bit value, y = 0
-            ((1usize << 44) - 1, (1usize << 44) - 1), // x = max 44-bit value, y = max 44-bit value
-            (1, 1),                                   // Minimal non-zero values
-            ((1usize << 43), (1usize << 43)),         // Carry propagates to the high bit
-            ((1usize << 43) - 1, 1),                  // Lower bits are max, single carry
+    fn test_analyze_adder() {
+        let swaps = [
+            ("z02", "g02"),
+            ("s04", "g04"),
+            ("z05", "c05"),
+            ("p06", "z06"),
         ];
+        let netlist = parse(&adder(8, &swaps)).unwrap();
+        let anomalies = netlist.analyze_adder().unwrap();
+
+        assert_eq!(anomalies.iter().map(|a| a.bit).collect_vec(), [2, 4, 5, 6]);
+        assert_eq!(
+            Day::part2(netlist).unwrap().to_string(),
+            "c05,g02,g04,p06,s04,z02,z05,z06"
+        );
+        assert_eq!(
+            anomalies[0].to_string(),
+            "bit 2: s02 XOR c01 isn't z02, swap g02 and z02"
+        );
+
+        let netlist = parse(&adder(4, &[("z03", "z04")])).unwrap();
+        let anomalies = netlist.analyze_adder().unwrap();
+        assert_eq!(
+            anomalies.iter().map(ToString::to_string).collect_vec(),
+            ["bit 3: s03 XOR c02 isn't z03, swap z04 and z03"]
+        );
+
+        // the carry out of bit 2 would depend on itself
+        assert!(parse(&adder(4, &[("c02", "z04")])).is_err());
+    }
 
-        for (x, y) in test_cases {
-            let expected = (x + y) & ((1usize << 45) - 1); // Expected value (mask to 45 bits)
-            machine.reset(x, y, 45);
-            machine.swaps = swaps.clone();
-            machine.run();
-            let result = machine.number('z');
-            assert_eq!(
-                result, expected,
-                "Failed for inputs x = {:#046b}, y = {:#046b} and expected z = {:#046b} but got result = {:#046b}",
-                x, y, expected, result
-            );
-        }
+    #[test]
+    fn test_2_sample() {
+        let netlist = parse(SAMPLE_3).unwrap();
+        let swaps = netlist.find_swaps(2, u64::bitand).unwrap();
 
-        let mut rng = thread_rng();
-        for _ in 0..1_000 {
-            // 1000 random tests, adjust count depending on performance
-            let x: usize = rng.gen_range(0..(1usize << 44));
-            let y: usize = rng.gen_range(0..(1usize << 44));
-            let expected = (x + y) & ((1usize << 45) - 1); // Expected value (simulate 45-bit addition)
-            machine.reset(x, y, 45);
-            machine.swaps = swaps.clone();
-            machine.run();
-            let result = machine.number('z');
-            assert_eq!(
-                result, expected,
-                "Failed for inputs x = {:#046b}, y = {:#046b} and expected z = {:#046b} but got result = {:#046b}",
-                x, y, expected, result
-            );
-        }
+        assert_eq!(swaps, "z00,z01,z02,z05");
     }
 }
@@ -1,4 +1,7 @@
+use crate::solution::Solution;
+use anyhow::Result;
 use itertools::Itertools;
+use std::fmt::Display;
 
 type KeyOrLock = [u8; 5];
 
@@ -47,6 +50,20 @@ pub fn possible_keys((keys, locks): &(Vec<KeyOrLock>, Vec<KeyOrLock>)) -> usize
         .count()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = (Vec<KeyOrLock>, Vec<KeyOrLock>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse(input))
+    }
+
+    fn part1(keys_and_locks: Self::Input<'_>) -> Result<impl Display> {
+        Ok(possible_keys(&keys_and_locks))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,7 +109,6 @@ mod tests {
 #.#.#
 #####
 ";
-    const INPUT: &str = include_str!("../../input/2024/25.txt");
 
     #[test]
     fn test_1_sample() {
@@ -100,11 +116,4 @@ mod tests {
 
         assert_eq!(possible_keys(&keys_and_locks), 3);
     }
-
-    #[test]
-    fn test_1() {
-        let keys_and_locks = parse(INPUT);
-
-        assert_eq!(possible_keys(&keys_and_locks), 2_900);
-    }
 }
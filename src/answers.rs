@@ -0,0 +1,256 @@
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The accepted answers for a single day, stored alongside the inputs as
+///
+/// ```toml
+/// part1 = "1583"
+/// part2 = "1627"
+/// wrong2 = ["1626", "2000"]
+/// ```
+///
+/// where `wrong1`/`wrong2` are guesses the server rejected, so they are never submitted again.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub wrong_1: Vec<String>,
+    pub wrong_2: Vec<String>,
+}
+
+/// The outcome of comparing a produced answer against the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// There is no recorded answer to compare against
+    Missing,
+}
+
+impl Answers {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        PathBuf::from(format!("answers/{year}/{day:02}.toml"))
+    }
+
+    /// Loads the recorded answers for a day. A day without an answers file has no answers yet.
+    ///
+    /// # Errors
+    ///
+    /// * if the file exists but cannot be read or parsed
+    pub fn load(year: u16, day: u8) -> Result<Self> {
+        let path = Self::path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .parse()
+                .with_context(|| format!("failed to parse answers {}", path.display())),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => {
+                Err(err).with_context(|| format!("failed to read answers {}", path.display()))
+            }
+        }
+    }
+
+    /// # Errors
+    ///
+    /// * if the answers file or its directory cannot be written
+    pub fn save(&self, year: u16, day: u8) -> Result<()> {
+        let path = Self::path(year, day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, self.to_string())
+            .with_context(|| format!("failed to write answers {}", path.display()))
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Records `answer` for `part` unless an answer is already recorded. Returns whether it was
+    /// recorded.
+    pub fn record(&mut self, part: u8, answer: &str) -> bool {
+        let slot = match part {
+            1 => &mut self.part_1,
+            2 => &mut self.part_2,
+            _ => return false,
+        };
+        if slot.is_some() {
+            return false;
+        }
+        *slot = Some(answer.to_string());
+        true
+    }
+
+    /// The guesses for `part` that the server rejected.
+    pub fn wrong(&self, part: u8) -> &[String] {
+        match part {
+            1 => &self.wrong_1,
+            2 => &self.wrong_2,
+            _ => &[],
+        }
+    }
+
+    /// Remembers a rejected guess for `part`. Returns whether it wasn't known yet.
+    pub fn record_wrong(&mut self, part: u8, answer: &str) -> bool {
+        let wrong = match part {
+            1 => &mut self.wrong_1,
+            2 => &mut self.wrong_2,
+            _ => return false,
+        };
+        if wrong.iter().any(|guess| guess == answer) {
+            return false;
+        }
+        wrong.push(answer.to_string());
+        true
+    }
+
+    pub fn check(&self, part: u8, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| anyhow!("expected `key = value`, got `{line}`"))?;
+            let value = value.trim();
+            match key.trim() {
+                "part1" => answers.part_1 = Some(unquote(value)),
+                "part2" => answers.part_2 = Some(unquote(value)),
+                "wrong1" => answers.wrong_1 = parse_list(value)?,
+                "wrong2" => answers.wrong_2 = parse_list(value)?,
+                key => bail!("unknown key `{key}`"),
+            }
+        }
+        Ok(answers)
+    }
+}
+
+fn unquote(value: &str) -> String {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+        .to_string()
+}
+
+/// Parses a list of quoted strings, such as `["1", "2,3"]`.
+fn parse_list(value: &str) -> Result<Vec<String>> {
+    let items = value
+        .strip_prefix('[')
+        .and_then(|value| value.strip_suffix(']'))
+        .ok_or_else(|| anyhow!("expected a list, got `{value}`"))?;
+    let mut list = vec![];
+    let mut rest = items.trim();
+    while !rest.is_empty() {
+        let (item, tail) = rest
+            .strip_prefix('"')
+            .and_then(|rest| rest.split_once('"'))
+            .ok_or_else(|| anyhow!("expected a quoted string in `{value}`"))?;
+        list.push(item.to_string());
+        rest = tail.trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+    Ok(list)
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(part_1) = &self.part_1 {
+            writeln!(f, "part1 = \"{part_1}\"")?;
+        }
+        if let Some(part_2) = &self.part_2 {
+            writeln!(f, "part2 = \"{part_2}\"")?;
+        }
+        for (key, wrong) in [("wrong1", &self.wrong_1), ("wrong2", &self.wrong_2)] {
+            if !wrong.is_empty() {
+                let list = wrong.iter().map(|guess| format!("\"{guess}\"")).join(", ");
+                writeln!(f, "{key} = [{list}]")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = "part1 = \"2,0,7\"\n\n# not solved yet\npart2 = 1627\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(answers.get(1), Some("2,0,7"));
+        assert_eq!(answers.get(2), Some("1627"));
+        assert!("part3 = \"1\"".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let answers = Answers {
+            part_1: Some("am,aq,by".to_string()),
+            wrong_2: vec!["1".to_string(), "am,aq".to_string()],
+            ..Answers::default()
+        };
+
+        assert_eq!(answers.to_string().parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+
+        assert_eq!(answers.check(1, "42"), Verdict::Missing);
+        assert!(answers.record(1, "42"));
+        assert!(!answers.record(1, "43"));
+        assert_eq!(answers.check(1, "42"), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, "43"),
+            Verdict::Incorrect {
+                expected: "42".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_wrong() {
+        let mut answers: Answers = "wrong1 = [\"12\", \"3,4\"]\nwrong2 = []".parse().unwrap();
+
+        assert_eq!(answers.wrong(1), ["12", "3,4"]);
+        assert!(answers.wrong(2).is_empty());
+        assert!(!answers.record_wrong(1, "12"));
+        assert!(answers.record_wrong(2, "7"));
+        assert_eq!(
+            answers.to_string(),
+            "wrong1 = [\"12\", \"3,4\"]\nwrong2 = [\"7\"]\n"
+        );
+        assert!("wrong1 = \"12\"".parse::<Answers>().is_err());
+    }
+}
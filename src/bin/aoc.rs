@@ -0,0 +1,333 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, ErrorKind, Read};
+use std::panic;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use advent_of_code_rust::answers::{Answers, Verdict};
+use advent_of_code_rust::registry::{self, Answer, Entry};
+use advent_of_code_rust::solution::Unsolved;
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use clap::Parser;
+use rayon::prelude::*;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Opts {
+    /// Runs every year when omitted
+    year: Option<u16>,
+    /// Runs every day of the year when omitted, and prints a table of answers and timings
+    day: Option<u8>,
+    /// Only run this part. Both parts are run when omitted
+    part: Option<u8>,
+    /// Path to the puzzle input, or `-` to read from stdin. Defaults to `input/{year}/{day}.txt`.
+    /// Answers are only checked against `answers/` when using the default input
+    #[arg(short, long)]
+    input: Option<String>,
+    /// Run the days in parallel when running more than one
+    #[arg(short, long)]
+    parallel: bool,
+    /// Save answers for parts that have no recorded answer yet
+    #[arg(short, long)]
+    record: bool,
+}
+
+fn main() -> Result<()> {
+    let Opts {
+        year,
+        day,
+        part,
+        input,
+        parallel,
+        record,
+    } = Opts::parse();
+
+    if let (Some(year), Some(day)) = (year, day) {
+        return run_day(year, day, part, input.as_deref(), record);
+    }
+
+    ensure!(
+        input.is_none(),
+        "--input can only be used with a single day"
+    );
+    let entries = registry::solutions()
+        .filter(|entry| year.map_or(true, |year| entry.year == year))
+        .collect::<Vec<_>>();
+    ensure!(!entries.is_empty(), "no solutions for {year:?}");
+    run_all(&entries, parallel, record)
+}
+
+fn run_day(year: u16, day: u8, part: Option<u8>, input: Option<&str>, record: bool) -> Result<()> {
+    let entry =
+        registry::find(year, day).ok_or_else(|| anyhow!("no solution for {year} day {day}"))?;
+
+    let (input, mut answers) = match input {
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .context("failed to read input from stdin")?;
+            (buf.trim_end().to_string(), None)
+        }
+        Some(path) => (registry::read_input(&PathBuf::from(path))?, None),
+        // answers are only meaningful for the input they were recorded against
+        None => (
+            registry::read_input(&registry::input_path(year, day))
+                .map_err(|err| missing_input(err, year, day))?,
+            Some(Answers::load(year, day)?),
+        ),
+    };
+
+    let mut incorrect = 0;
+    let mut recorded = false;
+    for part in part.map_or(vec![1, 2], |part| vec![part]) {
+        let answer = match entry.run(part, &input) {
+            Ok(answer) => answer.value,
+            Err(err) if err.is::<Unsolved>() => {
+                println!("Part {part}: {err}");
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        let Some(answers) = &mut answers else {
+            println!("Part {part}: {answer}");
+            continue;
+        };
+        match answers.check(part, &answer) {
+            Verdict::Correct => println!("Part {part}: {answer} (correct)"),
+            Verdict::Incorrect { expected } => {
+                incorrect += 1;
+                println!("Part {part}: {answer} (incorrect, expected {expected})");
+            }
+            Verdict::Missing if record => {
+                recorded |= answers.record(part, &answer);
+                println!("Part {part}: {answer} (recorded)");
+            }
+            Verdict::Missing => println!("Part {part}: {answer} (missing answer)"),
+        }
+    }
+
+    if let (true, Some(answers)) = (recorded, &answers) {
+        answers.save(year, day)?;
+    }
+    if incorrect > 0 {
+        bail!("{incorrect} incorrect answer(s)");
+    }
+
+    Ok(())
+}
+
+/// Replaces the io error for a missing input with a hint on where the input is expected.
+fn missing_input(err: anyhow::Error, year: u16, day: u8) -> anyhow::Error {
+    if is_missing_input(&err) {
+        anyhow!(
+            "missing input for {year} day {day}, expected it at {}",
+            registry::input_path(year, day).display()
+        )
+    } else {
+        err
+    }
+}
+
+fn is_missing_input(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<io::Error>()
+        .is_some_and(|err| err.kind() == ErrorKind::NotFound)
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    /// Fails when the input could not be read
+    parts: Result<[Result<Answer>; 2]>,
+}
+
+impl Row {
+    fn run(entry: &Entry) -> Self {
+        let parts = registry::read_input(&registry::input_path(entry.year, entry.day))
+            .map(|input| [1, 2].map(|part| run_part(entry, part, &input)));
+        Row {
+            year: entry.year,
+            day: entry.day,
+            parts,
+        }
+    }
+
+    /// Checks both parts against the recorded answers, returning the problems found.
+    fn check(&self, answers: &mut Answers, record: bool) -> Vec<Problem> {
+        let parts = match &self.parts {
+            Ok(parts) => parts,
+            Err(err) if is_missing_input(err) => return vec![Problem::MissingInput],
+            Err(_) => return vec![Problem::UnreadableInput],
+        };
+        let mut problems = vec![];
+        for (part, result) in (1..).zip(parts) {
+            match result {
+                Ok(answer) => match answers.check(part, &answer.value) {
+                    Verdict::Correct => {}
+                    Verdict::Incorrect { expected } => {
+                        eprintln!(
+                            "{} day {} part {part}: expected {expected}, got {}",
+                            self.year, self.day, answer.value
+                        );
+                        problems.push(Problem::Incorrect(part));
+                    }
+                    Verdict::Missing if record => {
+                        answers.record(part, &answer.value);
+                        problems.push(Problem::Recorded(part));
+                    }
+                    Verdict::Missing => problems.push(Problem::MissingAnswer(part)),
+                },
+                Err(err) if err.is::<Unsolved>() => {}
+                Err(err) => {
+                    eprintln!("{} day {} part {part}: {err:#}", self.year, self.day);
+                    problems.push(Problem::Error(part));
+                }
+            }
+        }
+        problems
+    }
+}
+
+/// Why a row of the table isn't simply `ok`.
+enum Problem {
+    MissingInput,
+    UnreadableInput,
+    Incorrect(u8),
+    /// The answer had no recorded answer to check against, and was recorded
+    Recorded(u8),
+    MissingAnswer(u8),
+    Error(u8),
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::MissingInput => write!(f, "missing input"),
+            Problem::UnreadableInput => write!(f, "unreadable input"),
+            Problem::Incorrect(part) => write!(f, "part {part} incorrect"),
+            Problem::Recorded(part) => write!(f, "part {part} recorded"),
+            Problem::MissingAnswer(part) => write!(f, "part {part} missing answer"),
+            Problem::Error(part) => write!(f, "part {part} error"),
+        }
+    }
+}
+
+/// Runs a single part, turning a panic into an error so one broken day doesn't take down the
+/// whole table.
+fn run_part(entry: &Entry, part: u8, input: &str) -> Result<Answer> {
+    panic::catch_unwind(|| entry.run(part, input)).unwrap_or_else(|_| Err(anyhow!("panicked")))
+}
+
+fn run_all(entries: &[Entry], parallel: bool, record: bool) -> Result<()> {
+    let mut answers = entries
+        .iter()
+        .map(|entry| Answers::load(entry.year, entry.day))
+        .collect::<Result<Vec<_>>>()?;
+    let rows: Vec<Row> = if parallel {
+        entries.par_iter().map(Row::run).collect()
+    } else {
+        entries.iter().map(Row::run).collect()
+    };
+
+    let header = [
+        "Year", "Day", "Part 1", "Part 2", "Parse", "Solve 1", "Solve 2", "Status",
+    ];
+    let mut table = vec![header.map(String::from)];
+    let mut total = [Duration::ZERO; 3];
+    let mut incorrect = 0;
+    for (row, answers) in rows.iter().zip(&mut answers) {
+        let before = answers.clone();
+        let problems = row.check(answers, record);
+        if *answers != before {
+            answers.save(row.year, row.day)?;
+        }
+        incorrect += problems
+            .iter()
+            .filter(|problem| matches!(problem, Problem::Incorrect(_)))
+            .count();
+        let status = if problems.is_empty() {
+            "ok".to_string()
+        } else {
+            let problems: Vec<String> = problems.iter().map(Problem::to_string).collect();
+            problems.join(", ")
+        };
+
+        let (answer_1, answer_2, times) = match &row.parts {
+            Ok([part_1, part_2]) => (
+                format_answer(part_1),
+                format_answer(part_2),
+                [
+                    part_1.as_ref().ok().map(|answer| answer.parse_time),
+                    part_1.as_ref().ok().map(|answer| answer.solve_time),
+                    part_2.as_ref().ok().map(|answer| answer.solve_time),
+                ],
+            ),
+            Err(_) => ("-".to_string(), "-".to_string(), [None; 3]),
+        };
+        for (total, time) in total.iter_mut().zip(times) {
+            *total += time.unwrap_or_default();
+        }
+        let [parse, solve_1, solve_2] = times.map(format_time);
+        table.push([
+            row.year.to_string(),
+            row.day.to_string(),
+            answer_1,
+            answer_2,
+            parse,
+            solve_1,
+            solve_2,
+            status,
+        ]);
+    }
+    let [parse, solve_1, solve_2] = total.map(|time| format_time(Some(time)));
+    table.push([
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        parse,
+        solve_1,
+        solve_2,
+        String::new(),
+    ]);
+    print_table(&table);
+
+    if incorrect > 0 {
+        bail!("{incorrect} incorrect answer(s)");
+    }
+
+    Ok(())
+}
+
+fn print_table<const N: usize>(table: &[[String; N]]) {
+    let mut widths = [0; N];
+    for cells in table {
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    for (i, cells) in table.iter().enumerate() {
+        if i == 1 || i == table.len() - 1 {
+            let rule = widths.iter().map(|&width| "-".repeat(width));
+            println!("{}", rule.collect::<Vec<_>>().join("-+-"));
+        }
+        let line = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>();
+        println!("{}", line.join(" | ").trim_end());
+    }
+}
+
+fn format_answer(result: &Result<Answer>) -> String {
+    match result {
+        Ok(answer) => answer.value.clone(),
+        Err(err) if err.is::<Unsolved>() => "-".to_string(),
+        Err(_) => "error".to_string(),
+    }
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    time.map_or_else(|| "-".to_string(), |time| format!("{time:.2?}"))
+}
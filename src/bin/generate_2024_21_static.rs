@@ -1,3 +1,4 @@
+use advent_of_code_rust::search;
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -57,38 +58,18 @@ fn calculate_shortest_path(start: char, end: char, keypad: Keypad) -> Vec<char>
         Keypad::Directional => &directional,
     };
 
-    let mut queue = std::collections::VecDeque::new();
-    let mut shortest_paths = Vec::new();
-    let mut found_length = None;
-
-    queue.push_back((start, vec![*grid.get(&start).unwrap()]));
-
-    while let Some((current, path)) = queue.pop_front() {
-        // If we reach the end, update shortest paths
-        if current == end {
-            if let Some(min_length) = found_length {
-                if path.len() == min_length {
-                    shortest_paths.push(path.clone());
-                }
-            } else {
-                found_length = Some(path.len());
-                shortest_paths.push(path.clone());
-            }
-            continue;
-        }
-
-        // Process the current node and its neighbors
-        if let Some(&cur_coords) = grid.get(&current) {
-            for (&neighbor, &neighbor_coords) in grid {
-                // Check if `neighbor` is adjacent and not yet visited
-                if is_adjacent(cur_coords, neighbor_coords) && !path.contains(&neighbor_coords) {
-                    let mut new_path = path.clone();
-                    new_path.push(neighbor_coords);
-                    queue.push_back((neighbor, new_path));
-                }
-            }
-        }
-    }
+    let start = grid[&start];
+    let end = grid[&end];
+    let shortest_paths = search::dijkstra_all(
+        start,
+        |&current| {
+            grid.values()
+                .filter(move |&&neighbor| is_adjacent(current, neighbor))
+                .map(|&neighbor| (neighbor, 1))
+        },
+        |&current| current == end,
+    )
+    .expect("every key can be reached from every other key");
 
     // Translate shortest path points into a series of directional changes
     // translates 0,1 0,2 1,2 into v>A
@@ -135,8 +116,8 @@ fn calculate_shortest_path(start: char, end: char, keypad: Keypad) -> Vec<char>
     }
 
     shortest_paths
-        .iter()
-        .map(|p| points_to_directions(p))
+        .paths()
+        .map(|p| points_to_directions(&p))
         // it will never be true that the shortest path backtracks
         .filter(|p| count_turns(p) <= 1)
         // get the cheapest path
@@ -1,47 +1,182 @@
-use advent_of_code_rust::_2024::_24::parse;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write as _};
+use std::path::PathBuf;
+
+use advent_of_code_rust::registry;
+use advent_of_code_rust::_2024::_24::{parse, Gate, GateKind, Netlist};
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
 use itertools::Itertools;
-use std::fs::File;
-use std::io::{self, Write};
-
-fn main() -> io::Result<()> {
-    // Define the input file path (modify this to reflect your actual file location)
-    let input = include_str!("../../input/2024/24.txt");
-    let output_file = "circuit.dot";
-
-    let gates = parse(input).gates;
-    let z = gates
-        .iter()
-        .filter(|g| g.output.starts_with('z'))
-        .map(|g| g.output.clone())
-        .sorted()
-        .join("->");
-    let x = z.replace('z', "x");
-    let y = z.replace('z', "y");
-
-    let and = gates
-        .iter()
-        .filter(|g| g.operation_str == "AND")
-        .map(|g| g.output.clone())
-        .join(" ");
-
-    let or = gates
-        .iter()
-        .filter(|g| g.operation_str == "OR")
-        .map(|g| g.output.clone())
-        .join(" ");
-
-    let xor = gates
-        .iter()
-        .filter(|g| g.operation_str == "XOR")
-        .map(|g| g.output.clone())
-        .join(" ");
-
-    // Open a file for writing
-    let mut file = File::create(output_file)?;
-
-    write!(
-        file,
-        "digraph G {{
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Opts {
+    /// The 2024 day 24 puzzle input
+    #[arg(default_value = "input/2024/24.txt")]
+    input: PathBuf,
+    /// Where to write the circuit, or `-` for stdout
+    #[arg(default_value = "circuit.dot")]
+    output: PathBuf,
+    /// Guessed from the extension of the output when omitted: `.dot`, `.mmd`, `.json` or `.blif`,
+    /// or DOT without one
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+    /// Label the wires with their values for these `x` and `y`. Only drawn in DOT and Mermaid
+    #[arg(short, long, requires = "y")]
+    x: Option<u64>,
+    /// See `--x`
+    #[arg(short, long, requires = "x")]
+    y: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    /// Graphviz
+    Dot,
+    Mermaid,
+    /// Yosys JSON netlist
+    Json,
+    Blif,
+}
+
+fn main() -> Result<()> {
+    let Opts {
+        input,
+        output,
+        format,
+        x,
+        y,
+    } = Opts::parse();
+    let format = match format {
+        Some(format) => format,
+        None => match output.extension().and_then(|e| e.to_str()) {
+            None | Some("dot" | "gv") => Format::Dot,
+            Some("mmd" | "mermaid") => Format::Mermaid,
+            Some("json") => Format::Json,
+            Some("blif") => Format::Blif,
+            _ => bail!(
+                "can't tell the format of {}, use --format",
+                output.display()
+            ),
+        },
+    };
+
+    let netlist = parse(&registry::read_input(&input)?)?;
+    let values = match (x, y) {
+        (Some(x), Some(y)) => {
+            let mut values = vec![None; netlist.wires()];
+            netlist.write_bus(&mut values, 'x', x);
+            netlist.write_bus(&mut values, 'y', y);
+            netlist.evaluate(&mut values)?;
+            values
+        }
+        _ => vec![],
+    };
+    // the wires of gates that don't fit an adder, and need their outputs swapped
+    let suspicious: HashSet<usize> = match netlist.analyze_adder() {
+        Ok(anomalies) => anomalies
+            .iter()
+            .flat_map(|anomaly| [&anomaly.swap.0, &anomaly.swap.1])
+            .filter_map(|name| netlist.wire(name))
+            .collect(),
+        Err(err) => {
+            eprintln!("not highlighting anything, the circuit isn't an adder: {err:#}");
+            HashSet::new()
+        }
+    };
+    let circuit = Circuit {
+        netlist: &netlist,
+        values: &values,
+        suspicious: &suspicious,
+    };
+
+    let text = match format {
+        Format::Dot => circuit.dot(),
+        Format::Mermaid => circuit.mermaid(),
+        Format::Json => circuit.yosys_json(),
+        Format::Blif => circuit.blif(),
+    };
+    if output.as_os_str() == "-" {
+        io::stdout().write_all(text.as_bytes())?;
+    } else {
+        fs::write(&output, text)
+            .with_context(|| format!("failed to write {}", output.display()))?;
+    }
+    Ok(())
+}
+
+struct Circuit<'a> {
+    netlist: &'a Netlist,
+    /// Empty when nothing was simulated.
+    values: &'a [Option<bool>],
+    suspicious: &'a HashSet<usize>,
+}
+
+impl Circuit<'_> {
+    fn name(&self, wire: usize) -> &str {
+        self.netlist.name(wire)
+    }
+
+    fn value(&self, wire: usize) -> Option<char> {
+        let value = self.values.get(wire).copied().flatten()?;
+        Some(if value { '1' } else { '0' })
+    }
+
+    /// The wires no gate drives.
+    fn inputs(&self) -> Vec<usize> {
+        (0..self.netlist.wires())
+            .filter(|&wire| self.netlist.driver(wire).is_none())
+            .sorted_by_key(|&wire| self.name(wire))
+            .collect()
+    }
+
+    /// The wires no gate reads.
+    fn outputs(&self) -> Vec<usize> {
+        let read: HashSet<usize> = self
+            .netlist
+            .gates()
+            .iter()
+            .flat_map(|gate| gate.inputs)
+            .collect();
+        (0..self.netlist.wires())
+            .filter(|wire| !read.contains(wire))
+            .sorted_by_key(|&wire| self.name(wire))
+            .collect()
+    }
+
+    fn gates_of(&self, kind: GateKind) -> impl Iterator<Item = &Gate> {
+        self.netlist
+            .gates()
+            .iter()
+            .filter(move |gate| gate.kind == kind)
+    }
+
+    /// The wires as nodes, colored by what drives them, with an edge from each input of a gate to
+    /// its output.
+    fn dot(&self) -> String {
+        let bus = |prefix: char| {
+            self.netlist
+                .bus(prefix)
+                .iter()
+                .map(|&wire| self.name(wire))
+                .join("->")
+        };
+        let (x, y, z) = (bus('x'), bus('y'), bus('z'));
+        let kind_names = |kind: GateKind| {
+            self.gates_of(kind)
+                .map(|gate| self.name(gate.output))
+                .join(" ")
+        };
+        let (and, or, xor) = (
+            kind_names(GateKind::And),
+            kind_names(GateKind::Or),
+            kind_names(GateKind::Xor),
+        );
+
+        let mut dot = format!(
+            "digraph G {{
 subgraph output_z {{
    node [style=filled,color=green]
     {z}
@@ -67,18 +202,300 @@ subgraph gates_xor {{
     {xor}
 }}
 "
-    )?;
+        );
+        for &wire in self
+            .suspicious
+            .iter()
+            .sorted_by_key(|&&wire| self.name(wire))
+        {
+            writeln!(dot, "    {} [penwidth=4,fontcolor=red];", self.name(wire)).unwrap();
+        }
+        for gate in self.netlist.gates() {
+            let output = self.name(gate.output);
+            for input in gate.inputs {
+                let label = self
+                    .value(input)
+                    .map(|value| format!(" [label={value}]"))
+                    .unwrap_or_default();
+                writeln!(dot, "    {} -> {output}{label};", self.name(input)).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// A flowchart with a node per gate, labelled with the wire it drives. The node ids are
+    /// prefixed, since a wire could be named after a Mermaid keyword like `end`.
+    fn mermaid(&self) -> String {
+        let id = |wire: usize| format!("w_{}", self.name(wire));
+        let mut mermaid = String::from("flowchart LR\n");
+        for &wire in &self.inputs() {
+            writeln!(mermaid, "    {}([{}])", id(wire), self.name(wire)).unwrap();
+        }
+        for gate in self.netlist.gates() {
+            let output = id(gate.output);
+            let name = self.name(gate.output);
+            writeln!(mermaid, "    {output}[\"{name}<br>{}\"]", gate.kind).unwrap();
+            for input in gate.inputs {
+                let arrow = self
+                    .value(input)
+                    .map_or("-->".to_string(), |value| format!("-->|{value}|"));
+                writeln!(mermaid, "    {} {arrow} {output}", id(input)).unwrap();
+            }
+        }
 
-    // Write outputs to the file
-    for gate in gates {
+        writeln!(mermaid, "    classDef gate_and fill:pink").unwrap();
+        writeln!(mermaid, "    classDef gate_or fill:yellow").unwrap();
+        writeln!(mermaid, "    classDef gate_xor fill:lightblue").unwrap();
         writeln!(
-            file,
-            "    {} -> {}; {} -> {};",
-            gate.input_a, gate.output, gate.input_b, gate.output
-        )?;
+            mermaid,
+            "    classDef suspicious stroke:red,stroke-width:4px"
+        )
+        .unwrap();
+        for (kind, class) in [
+            (GateKind::And, "gate_and"),
+            (GateKind::Or, "gate_or"),
+            (GateKind::Xor, "gate_xor"),
+        ] {
+            let outputs = self
+                .gates_of(kind)
+                .map(|gate| id(gate.output))
+                .collect_vec();
+            if !outputs.is_empty() {
+                writeln!(mermaid, "    class {} {class}", outputs.join(",")).unwrap();
+            }
+        }
+        if !self.suspicious.is_empty() {
+            let ids = self.suspicious.iter().map(|&wire| id(wire)).sorted();
+            writeln!(mermaid, "    class {} suspicious", ids.format(",")).unwrap();
+        }
+        mermaid
     }
 
-    writeln!(file, "}}")?;
+    /// A module in the JSON that `yosys -p write_json` writes, with the gates as Yosys' internal
+    /// single bit cells. Yosys keeps bits 0 and 1 for the constants, so wire `n` is bit `n + 2`.
+    fn yosys_json(&self) -> String {
+        let bit = |wire: usize| wire + 2;
+        let ports = [("input", self.inputs()), ("output", self.outputs())]
+            .into_iter()
+            .flat_map(|(direction, wires)| {
+                wires.into_iter().map(move |wire| {
+                    format!(
+                        "        \"{}\": {{ \"direction\": \"{direction}\", \"bits\": [ {} ] }}",
+                        self.name(wire),
+                        bit(wire)
+                    )
+                })
+            })
+            .join(",\n");
+        let cells = self
+            .netlist
+            .gates()
+            .iter()
+            .map(|gate| {
+                let output = self.name(gate.output);
+                let cell_type = match gate.kind {
+                    GateKind::And => "$_AND_",
+                    GateKind::Or => "$_OR_",
+                    GateKind::Xor => "$_XOR_",
+                };
+                let suspicious = u8::from(self.suspicious.contains(&gate.output));
+                let [a, b] = gate.inputs.map(bit);
+                format!(
+                    "        \"{output}_{}\": {{
+          \"type\": \"{cell_type}\",
+          \"attributes\": {{ \"suspicious\": \"{suspicious}\" }},
+          \"port_directions\": {{ \"A\": \"input\", \"B\": \"input\", \"Y\": \"output\" }},
+          \"connections\": {{ \"A\": [ {a} ], \"B\": [ {b} ], \"Y\": [ {} ] }}
+        }}",
+                    gate.kind.to_string().to_lowercase(),
+                    bit(gate.output)
+                )
+            })
+            .join(",\n");
+        let netnames = (0..self.netlist.wires())
+            .map(|wire| {
+                format!(
+                    "        \"{}\": {{ \"hide_name\": 0, \"bits\": [ {} ] }}",
+                    self.name(wire),
+                    bit(wire)
+                )
+            })
+            .join(",\n");
 
-    Ok(())
+        format!(
+            "{{
+  \"creator\": \"generate_2024_24_circuit\",
+  \"modules\": {{
+    \"circuit\": {{
+      \"ports\": {{
+{ports}
+      }},
+      \"cells\": {{
+{cells}
+      }},
+      \"netnames\": {{
+{netnames}
+      }}
+    }}
+  }}
+}}
+"
+        )
+    }
+
+    /// A Berkeley Logic Interchange Format model, with a comment on the suspicious gates.
+    fn blif(&self) -> String {
+        let names = |wires: Vec<usize>| wires.into_iter().map(|wire| self.name(wire)).join(" ");
+        let mut blif = format!(
+            ".model circuit\n.inputs {}\n.outputs {}\n",
+            names(self.inputs()),
+            names(self.outputs())
+        );
+        for gate in self.netlist.gates() {
+            let [a, b] = gate.inputs.map(|wire| self.name(wire));
+            let output = self.name(gate.output);
+            if self.suspicious.contains(&gate.output) {
+                writeln!(blif, "# {output} doesn't fit the adder").unwrap();
+            }
+            // the rows of the truth table that output 1
+            let ones = match gate.kind {
+                GateKind::And => "11 1\n",
+                GateKind::Or => "1- 1\n-1 1\n",
+                GateKind::Xor => "10 1\n01 1\n",
+            };
+            write!(blif, ".names {a} {b} {output}\n{ones}").unwrap();
+        }
+        blif.push_str(".end\n");
+        blif
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a half adder, with the carry on a wire named after a Mermaid keyword
+    const SAMPLE: &str = "\
+x00: 1
+y00: 1
+
+x00 XOR y00 -> z00
+x00 AND y00 -> end
+";
+
+    /// The sample's netlist, simulated with both inputs set, with the carry marked as suspicious.
+    fn half_adder() -> (Netlist, Vec<Option<bool>>, HashSet<usize>) {
+        let netlist = parse(SAMPLE).unwrap();
+        let mut values = vec![None; netlist.wires()];
+        netlist.write_bus(&mut values, 'x', 1);
+        netlist.write_bus(&mut values, 'y', 1);
+        netlist.evaluate(&mut values).unwrap();
+        let suspicious = HashSet::from([netlist.wire("end").unwrap()]);
+        (netlist, values, suspicious)
+    }
+
+    #[test]
+    fn test_dot() {
+        let (netlist, values, suspicious) = half_adder();
+        let circuit = Circuit {
+            netlist: &netlist,
+            values: &values,
+            suspicious: &suspicious,
+        };
+        let dot = circuit.dot();
+
+        assert!(dot.starts_with("digraph G {\n"));
+        assert!(dot.contains("color=pink]\n    end\n}"));
+        assert!(dot.contains("color=lightblue];\n    z00\n}"));
+        assert!(dot.contains("    end [penwidth=4,fontcolor=red];\n"));
+        assert!(dot.contains("    x00 -> z00 [label=1];\n    y00 -> z00 [label=1];\n"));
+        assert!(dot.ends_with("    y00 -> end [label=1];\n}\n"));
+    }
+
+    #[test]
+    fn test_mermaid() {
+        let (netlist, values, suspicious) = half_adder();
+        let circuit = Circuit {
+            netlist: &netlist,
+            values: &values,
+            suspicious: &suspicious,
+        };
+
+        // there are no OR gates, so there is no line for their class
+        assert_eq!(
+            circuit.mermaid(),
+            "\
+flowchart LR
+    w_x00([x00])
+    w_y00([y00])
+    w_z00[\"z00<br>XOR\"]
+    w_x00 -->|1| w_z00
+    w_y00 -->|1| w_z00
+    w_end[\"end<br>AND\"]
+    w_x00 -->|1| w_end
+    w_y00 -->|1| w_end
+    classDef gate_and fill:pink
+    classDef gate_or fill:yellow
+    classDef gate_xor fill:lightblue
+    classDef suspicious stroke:red,stroke-width:4px
+    class w_end gate_and
+    class w_z00 gate_xor
+    class w_end suspicious
+"
+        );
+    }
+
+    #[test]
+    fn test_yosys_json() {
+        let (netlist, values, suspicious) = half_adder();
+        let circuit = Circuit {
+            netlist: &netlist,
+            values: &values,
+            suspicious: &suspicious,
+        };
+        let json = circuit.yosys_json();
+
+        assert!(json.contains(r#""x00": { "direction": "input", "bits": [ 2 ] }"#));
+        assert!(json.contains(r#""end": { "direction": "output", "bits": [ 5 ] }"#));
+        assert!(json.contains(
+            r#""z00_xor": {
+          "type": "$_XOR_",
+          "attributes": { "suspicious": "0" },"#
+        ));
+        assert!(json.contains(
+            r#""end_and": {
+          "type": "$_AND_",
+          "attributes": { "suspicious": "1" },
+          "port_directions": { "A": "input", "B": "input", "Y": "output" },
+          "connections": { "A": [ 2 ], "B": [ 3 ], "Y": [ 5 ] }"#
+        ));
+        assert!(json.contains(r#""end": { "hide_name": 0, "bits": [ 5 ] }"#));
+    }
+
+    #[test]
+    fn test_blif() {
+        let (netlist, values, suspicious) = half_adder();
+        let circuit = Circuit {
+            netlist: &netlist,
+            values: &values,
+            suspicious: &suspicious,
+        };
+
+        assert_eq!(
+            circuit.blif(),
+            "\
+.model circuit
+.inputs x00 y00
+.outputs end z00
+.names x00 y00 z00
+10 1
+01 1
+# end doesn't fit the adder
+.names x00 y00 end
+11 1
+.end
+"
+        );
+    }
 }
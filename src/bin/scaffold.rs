@@ -1,155 +1,262 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
 use std::fs;
-use std::fs::{create_dir_all, File, OpenOptions};
-use std::io::prelude::*;
-use std::path::Path;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
+use advent_of_code_rust::client::{Client, DEFAULT_BASE_URL};
+use advent_of_code_rust::{puzzle, registry};
+use anyhow::{ensure, Context, Result};
 use clap::Parser;
-use reqwest::header::HeaderMap;
+use regex::Regex;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Opts {
-    year: String,
-    start: i32,
+    year: u16,
+    start: u8,
+    /// Only needed when an input has not been downloaded yet. Falls back to `AOC_SESSION`
     session: Option<String>,
-    end: Option<i32>,
+    end: Option<u8>,
+    /// Where to download inputs from, e.g. a local stand-in server
+    #[arg(long, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Minimum time between requests, in milliseconds
+    #[arg(long, default_value_t = 1_000)]
+    delay: u64,
+    /// Read the puzzle description from a saved HTML page instead of downloading it. Only valid
+    /// for a single day
+    #[arg(long)]
+    puzzle: Option<PathBuf>,
+    /// Overwrite solution files that already exist
+    #[arg(short, long)]
+    force: bool,
 }
 
-fn main() {
-    download_input();
-    scaffold_files();
-}
-
-fn download_input() {
-    let Opts {
-        start,
-        end,
-        year,
-        session,
-    } = Opts::parse();
-    let session = session.expect("session is required");
+fn main() -> Result<()> {
+    let opts = Opts::parse();
+    ensure!(
+        opts.puzzle.is_none() || opts.end.is_none(),
+        "--puzzle can only be used with a single day"
+    );
 
-    let get_input_file = |year: &str, day: i32| -> String { format!("input/{year}/{day:02}.txt") };
+    let session = opts
+        .session
+        .clone()
+        .or_else(|| std::env::var("AOC_SESSION").ok());
+    let mut client = Client::new(&opts.base_url, session, Duration::from_millis(opts.delay))?;
+    download_inputs(&opts, &mut client)?;
+    scaffold_files(&opts, &mut client)
+}
 
-    let get_url = |year: &str, day: i32| -> String {
-        format!("https://adventofcode.com/{year}/day/{day}/input")
-    };
-    // Send a GET request
-    let mut headers = HeaderMap::new();
-    headers.insert(
-        "Cookie",
-        format!("session={session}")
-            .parse()
-            .expect("unable to make header from session"),
-    );
-    let client = reqwest::blocking::Client::builder()
-        .default_headers(headers)
-        .build()
-        .expect("built a client");
-
-    for day in start..=end.unwrap_or(start) {
-        let url = get_url(&year, day);
-        let path = get_input_file(&year, day);
-
-        let mut resp = client.get(&url).send().expect("made a request");
-
-        // Check for status code 200
-        if resp.status().is_success() {
-            // Ensure parent directory exists before writing
-            let output_path = Path::new(&path);
-            if let Some(dir) = output_path.parent() {
-                create_dir_all(dir).expect("should have created dir");
-            }
+fn download_inputs(opts: &Opts, client: &mut Client) -> Result<()> {
+    for day in opts.start..=opts.end.unwrap_or(opts.start) {
+        let path = registry::input_path(opts.year, day);
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            println!("{} already exists, skipping", path.display());
+            continue;
+        }
 
-            // Write the response bytes to a file
-            let mut file = File::create(path).expect("should have created input file");
-            resp.copy_to(&mut file).expect("able to write to file");
-            println!("Successfully created file");
-        } else {
-            println!("Received response status: {}", resp.status());
+        // download the whole input before writing, so a failed request never leaves behind a
+        // partial file that would be mistaken for a cached input next time
+        let input = client.input(opts.year, day)?;
+        if let Some(dir) = path.parent() {
+            create_dir_all(dir)?;
         }
+        fs::write(&path, input).with_context(|| format!("failed to write {}", path.display()))?;
+        println!("Downloaded {}", path.display());
     }
+
+    Ok(())
+}
+
+/// Collects the example blocks of a day's puzzle description. Scaffolding carries on without
+/// examples when the description can't be fetched.
+fn samples(opts: &Opts, client: &mut Client, day: u8) -> Result<Vec<String>> {
+    let html = match &opts.puzzle {
+        Some(path) => fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?,
+        None => match client.puzzle(opts.year, day) {
+            Ok(html) => html,
+            Err(err) => {
+                eprintln!("Skipping examples for day {day}: {err:#}");
+                return Ok(vec![]);
+            }
+        },
+    };
+    Ok(puzzle::extract_samples(&html))
 }
 
-fn scaffold_files() {
-    let Opts {
+fn scaffold_files(opts: &Opts, client: &mut Client) -> Result<()> {
+    let &Opts {
+        year,
         start,
         end,
-        year,
-        session: _,
-    } = Opts::parse();
-
-    let src_directory = format!("src/_{year}");
-
-    create_dir_all(&src_directory).expect("Failed to create src directory");
+        force,
+        ..
+    } = opts;
+    let src_directory = PathBuf::from(format!("src/_{year}"));
 
-    let mut mod_file = OpenOptions::new()
-        .write(true)
-        .append(true)
-        .open(format!("{}/mod.rs", &src_directory))
-        .or_else(|_| File::create(format!("{}/mod.rs", &src_directory)))
-        .expect("Unable to open file");
+    create_dir_all(&src_directory)
+        .with_context(|| format!("failed to create {}", src_directory.display()))?;
 
     for i in start..=end.unwrap_or(start) {
-        let day = format!("{i:02}");
-        let file_name = format!("{src_directory}/_{day}.rs");
+        let file_name = src_directory.join(format!("_{i:02}.rs"));
+        if file_name.exists() && !force {
+            println!(
+                "{} already exists, skipping (use --force to overwrite)",
+                file_name.display()
+            );
+            continue;
+        }
 
-        fs::write(
-            &file_name,
-            format!(
-                r##"use anyhow::{{anyhow, Result}};
+        let template = r#"use crate::solution::Solution;
+use anyhow::{anyhow, Result};
 use itertools::Itertools;
+use std::fmt::Display;
 
-pub fn parse(input: &str) -> Result<i32> {{
+pub fn parse(input: &str) -> Result<i32> {
     input
         .lines()
-        .map(|l| {{
+        .map(|l| {
             l.parse::<i32>()
-                .map_err(|e| anyhow!("failed to parse input: {{}}", e))
-        }})
+                .map_err(|e| anyhow!("failed to parse input: {}", e))
+        })
         .process_results(|res| res.sum())
-}}
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<impl Display> {
+        Ok(input)
+    }
+}
 
 #[cfg(test)]
-mod tests {{
+mod tests {
     use super::*;
     
     const SAMPLE: &str = "\
 ";
-    const INPUT: &str = include_str!("../../input/{year}/{day}.txt");
 
     #[test]
-    fn test_1_sample() {{
+    fn test_1_sample() {
         let input = parse(SAMPLE).unwrap();
 
         assert_eq!(input, 1 + 1);
-    }}
+    }
 
     #[test]
-    fn test_1() {{
-        let input = parse(INPUT).unwrap();
+    fn test_2_sample() {
+        let input = parse(SAMPLE).unwrap();
 
         assert_eq!(input, 1 + 1);
-    }}
+    }
+}"#;
+        let samples = samples(opts, client, i)?;
+        let contents = if samples.is_empty() {
+            template.to_string()
+        } else {
+            template.replace(
+                "    const SAMPLE: &str = \"\\\n\";\n",
+                &puzzle::sample_consts(&samples),
+            )
+        };
+        fs::write(&file_name, contents)
+            .with_context(|| format!("failed to write {}", file_name.display()))?;
+        println!("Created {}", file_name.display());
+    }
 
-    #[test]
-    fn test_2_sample() {{
-        let input = parse(SAMPLE).unwrap();
+    write_mod_file(&src_directory, year)?;
+    register_year(year)
+}
 
-        assert_eq!(input, 1 + 1);
-    }}
+/// Regenerates the year's `mod.rs` from the day files on disk, so it is always sorted and never
+/// lists a day twice, however often the scaffold is run.
+fn write_mod_file(src_directory: &Path, year: u16) -> Result<()> {
+    let day_file = Regex::new(r"^_(\d{2})\.rs$").expect("valid regex");
+    let mut days = BTreeSet::new();
+    for entry in fs::read_dir(src_directory)? {
+        let name = entry?.file_name();
+        if let Some(captures) = day_file.captures(&name.to_string_lossy()) {
+            days.insert(captures[1].parse::<u8>()?);
+        }
+    }
 
-    #[test]
-    fn test_2() {{
-        let input = parse(INPUT).unwrap();
+    let mut contents = String::new();
+    for day in &days {
+        writeln!(contents, "pub mod _{day:02};")?;
+    }
+    contents.push_str("\nuse crate::registry::Entry;\n\npub const SOLUTIONS: &[Entry] = &[\n");
+    for day in &days {
+        writeln!(contents, "    Entry::new::<_{day:02}::Day>({year}, {day}),")?;
+    }
+    contents.push_str("];\n");
 
-        assert_eq!(input, 1 + 1);
-    }}
-}}"##,
-            ),
-        )
-        .expect("Unable to write src file");
+    let path = src_directory.join("mod.rs");
+    if fs::read_to_string(&path).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+    fs::write(&path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Adds the year module to `src/lib.rs` and its solutions to `YEARS`, if it isn't there yet.
+fn register_year(year: u16) -> Result<()> {
+    let path = Path::new("src/lib.rs");
+    let lib =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let year_module = Regex::new(r"^pub mod _(\d{4});$").expect("valid regex");
+    let mut years = lib
+        .lines()
+        .filter_map(|line| year_module.captures(line))
+        .map(|captures| captures[1].parse::<u16>())
+        .collect::<Result<BTreeSet<_>, _>>()?;
+    if !years.insert(year) {
+        return Ok(());
+    }
 
-        writeln!(mod_file, "pub mod _{};", &day).expect("Unable to write to mod line");
+    let mut contents = String::new();
+    let mut lines = lib.lines();
+    let mut modules_written = false;
+    while let Some(line) = lines.next() {
+        if year_module.is_match(line) {
+            if !modules_written {
+                for year in &years {
+                    writeln!(contents, "pub mod _{year};")?;
+                }
+                modules_written = true;
+            }
+        } else if line.starts_with("pub const YEARS") {
+            // skip the rest of the old list, however rustfmt wrapped it
+            if !line.ends_with("];") {
+                lines.find(|line| line.ends_with("];"));
+            }
+            let solutions = years
+                .iter()
+                .map(|year| format!("_{year}::SOLUTIONS"))
+                .collect::<Vec<_>>();
+            writeln!(
+                contents,
+                "pub const YEARS: &[&[registry::Entry]] = &[{}];",
+                solutions.join(", ")
+            )?;
+        } else {
+            writeln!(contents, "{line}")?;
+        }
     }
+
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))?;
+    println!(
+        "Registered {year} in {}, run `cargo fmt` to tidy it up",
+        path.display()
+    );
+    Ok(())
 }
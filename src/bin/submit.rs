@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use advent_of_code_rust::answers::Answers;
+use advent_of_code_rust::client::{Client, Submission, DEFAULT_BASE_URL};
+use advent_of_code_rust::registry;
+use anyhow::{anyhow, bail, ensure, Result};
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Opts {
+    year: u16,
+    day: u8,
+    part: u8,
+    /// Computed by running the solution on `input/{year}/{day}.txt` when omitted
+    answer: Option<String>,
+    /// Falls back to `AOC_SESSION`
+    #[arg(short, long)]
+    session: Option<String>,
+    /// Where to submit answers to, e.g. a local stand-in server
+    #[arg(long, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+fn main() -> Result<()> {
+    let Opts {
+        year,
+        day,
+        part,
+        answer,
+        session,
+        base_url,
+    } = Opts::parse();
+    ensure!(part == 1 || part == 2, "part must be 1 or 2, got {part}");
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve(year, day, part)?,
+    };
+
+    // never spend a submission on something we already know the verdict of
+    let mut answers = Answers::load(year, day)?;
+    if let Some(expected) = answers.get(part) {
+        if *expected == answer {
+            println!("{answer} is already recorded as the answer");
+            return Ok(());
+        }
+        bail!("part {part} is already solved with {expected}, not submitting {answer}");
+    }
+    if answers.wrong(part).contains(&answer) {
+        bail!("{answer} was already rejected for part {part}");
+    }
+
+    let session = session.or_else(|| std::env::var("AOC_SESSION").ok());
+    let mut client = Client::new(&base_url, session, Duration::ZERO)?;
+    let submission = client.submit(year, day, part, &answer)?;
+    println!("Part {part}: {answer} ({submission})");
+
+    if submission == Submission::Correct {
+        answers.record(part, &answer);
+    } else if submission.is_wrong() {
+        answers.record_wrong(part, &answer);
+    } else {
+        bail!("{answer} was not accepted");
+    }
+    answers.save(year, day)?;
+
+    if submission.is_wrong() {
+        bail!("{answer} is not the right answer");
+    }
+    Ok(())
+}
+
+fn solve(year: u16, day: u8, part: u8) -> Result<String> {
+    let entry =
+        registry::find(year, day).ok_or_else(|| anyhow!("no solution for {year} day {day}"))?;
+    let input = registry::read_input(&registry::input_path(year, day))?;
+    let answer = entry.run(part, &input)?.value;
+    println!("Part {part}: {answer}");
+    Ok(answer)
+}
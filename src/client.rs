@@ -0,0 +1,312 @@
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use reqwest::blocking;
+use reqwest::header::COOKIE;
+use std::fmt::{Display, Formatter};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the Advent of Code servers, as requested by its automation guidelines.
+const AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal puzzle input downloader)"
+);
+
+/// A client for the Advent of Code website. Requests are spaced at least `delay` apart so that
+/// downloading a range of days doesn't hammer the server.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    delay: Duration,
+    last_request: Option<Instant>,
+    http: blocking::Client,
+}
+
+impl Client {
+    /// # Errors
+    ///
+    /// * if the underlying HTTP client cannot be built
+    pub fn new(base_url: &str, session: Option<String>, delay: Duration) -> Result<Self> {
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            delay,
+            last_request: None,
+            http: blocking::Client::builder().user_agent(AGENT).build()?,
+        })
+    }
+
+    /// Downloads the puzzle input for a day.
+    ///
+    /// # Errors
+    ///
+    /// * if there is no session to authenticate with
+    /// * if the request fails or the server responds with anything other than success
+    pub fn input(&mut self, year: u16, day: u8) -> Result<String> {
+        if self.session.is_none() {
+            bail!("a session is required to download the input for {year} day {day}");
+        }
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// Downloads the puzzle description for a day as HTML. Without a session only the first part
+    /// of the description is included.
+    ///
+    /// # Errors
+    ///
+    /// * if the request fails or the server responds with anything other than success
+    pub fn puzzle(&mut self, year: u16, day: u8) -> Result<String> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    /// Submits an answer for one part of a day.
+    ///
+    /// # Errors
+    ///
+    /// * if there is no session to authenticate with
+    /// * if the request fails or the server responds with anything other than success
+    pub fn submit(&mut self, year: u16, day: u8, part: u8, answer: &str) -> Result<Submission> {
+        if self.session.is_none() {
+            bail!("a session is required to submit an answer for {year} day {day}");
+        }
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let request = self
+            .http
+            .post(&url)
+            .form(&[("level", level.as_str()), ("answer", answer)]);
+        Ok(Submission::from_response(&self.send(&url, request)?))
+    }
+
+    fn get(&mut self, path: &str) -> Result<String> {
+        let url = format!("{}{path}", self.base_url);
+        let request = self.http.get(&url);
+        self.send(&url, request)
+    }
+
+    fn send(&mut self, url: &str, mut request: blocking::RequestBuilder) -> Result<String> {
+        self.throttle();
+
+        if let Some(session) = &self.session {
+            request = request.header(COOKIE, format!("session={session}"));
+        }
+        let response = request
+            .send()
+            .with_context(|| format!("failed to request {url}"))?;
+        let status = response.status();
+        if !status.is_success() {
+            bail!("{url} responded with {status}");
+        }
+        response
+            .text()
+            .with_context(|| format!("failed to read the response from {url}"))
+    }
+
+    fn throttle(&mut self) {
+        if let Some(last_request) = self.last_request {
+            thread::sleep(self.delay.saturating_sub(last_request.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint on which way
+    Incorrect,
+    /// An answer was submitted too recently. Holds the remaining time, when the server says so
+    Wait(Option<Duration>),
+    /// The part is already solved, or its previous part isn't yet
+    WrongLevel,
+    /// Anything else, with the text of the message
+    Unknown(String),
+}
+
+impl Submission {
+    /// Interprets the HTML page returned for a submission by the message in its `<article>`.
+    pub fn from_response(html: &str) -> Self {
+        let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").expect("valid regex");
+        let tag = Regex::new(r"<[^>]*>").expect("valid regex");
+        let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").expect("valid regex");
+
+        let message = article
+            .captures(html)
+            .map_or(html, |captures| captures.get(1).unwrap().as_str());
+        let message = tag.replace_all(message, "");
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if message.contains("That's the right answer") {
+            Submission::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Submission::TooHigh
+            } else if message.contains("too low") {
+                Submission::TooLow
+            } else {
+                Submission::Incorrect
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Submission::Wait(wait.captures(&message).map(|captures| {
+                let minutes = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                let seconds: u64 = captures[2].parse().unwrap();
+                Duration::from_secs(minutes * 60 + seconds)
+            }))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Submission::WrongLevel
+        } else {
+            Submission::Unknown(message)
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Submission::TooHigh | Submission::TooLow | Submission::Incorrect
+        )
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "correct"),
+            Submission::TooHigh => write!(f, "incorrect, too high"),
+            Submission::TooLow => write!(f, "incorrect, too low"),
+            Submission::Incorrect => write!(f, "incorrect"),
+            Submission::Wait(Some(time)) => write!(f, "answered too recently, wait {time:?}"),
+            Submission::Wait(None) => write!(f, "answered too recently"),
+            Submission::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+            Submission::Unknown(message) => write!(f, "unrecognized response: {message}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Serves a single canned response on a local port, returning the base URL and a handle that
+    /// yields the raw request that was received, headers and body.
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                request.push_str(line);
+                request.push('\n');
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = serve_once("200 OK", "1\n2\n3\n");
+        let mut client = Client::new(&base_url, Some("abc".to_string()), Duration::ZERO).unwrap();
+
+        assert_eq!(client.input(2024, 7).unwrap(), "1\n2\n3\n");
+
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2024/day/7/input "));
+        assert!(request.contains("cookie: session=abc"));
+        assert!(request.contains(&format!("user-agent: {}", AGENT.to_lowercase())));
+    }
+
+    #[test]
+    fn test_input_failure() {
+        let (base_url, server) = serve_once("404 Not Found", "");
+        let mut client = Client::new(&base_url, Some("abc".to_string()), Duration::ZERO).unwrap();
+
+        assert!(client.input(2024, 26).is_err());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_puzzle_without_session() {
+        let (base_url, server) = serve_once("200 OK", "<pre><code>1</code></pre>");
+        let mut client = Client::new(&base_url, None, Duration::ZERO).unwrap();
+
+        assert_eq!(client.puzzle(2024, 7).unwrap(), "<pre><code>1</code></pre>");
+
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2024/day/7 "));
+        assert!(!request.contains("cookie:"));
+    }
+
+    #[test]
+    fn test_input_without_session() {
+        let mut client = Client::new(DEFAULT_BASE_URL, None, Duration::ZERO).unwrap();
+
+        assert!(client.input(2024, 1).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let mut client = Client::new(&base_url, Some("abc".to_string()), Duration::ZERO).unwrap();
+
+        assert_eq!(
+            client.submit(2024, 17, 1, "2,0,7").unwrap(),
+            Submission::Correct
+        );
+
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("post /2024/day/17/answer "));
+        assert!(request.contains("cookie: session=abc"));
+        assert!(request.ends_with("level=1&answer=2%2c0%2c7"));
+    }
+
+    #[test]
+    fn test_submission_from_response() {
+        let responses = [
+            ("<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.</p></article>", Submission::TooHigh),
+            ("<article><p>That's not the right answer; your answer is too low.</p></article>", Submission::TooLow),
+            ("<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>", Submission::Incorrect),
+            ("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2024/day/17\">[Return to Day 17]</a></p></article>", Submission::Wait(Some(Duration::from_secs(65)))),
+            ("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait.</p></article>", Submission::Wait(Some(Duration::from_secs(42)))),
+            ("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>", Submission::WrongLevel),
+            ("<article><p>Something\n else.</p></article>", Submission::Unknown("Something else.".to_string())),
+        ];
+
+        for (response, expected) in responses {
+            assert_eq!(Submission::from_response(response), expected);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating: the state after `start` steps comes back every
+/// `length` steps from then on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step that has the same state as `step`.
+    pub fn earliest_equivalent(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// Steps from `start` until a state repeats, remembering every state on the way. `step` has to
+/// depend on nothing but the state it is given, and the states have to repeat eventually.
+pub fn find_cycle<S: Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for steps in 0.. {
+        if let Some(&first) = seen.get(&state) {
+            return Cycle {
+                start: first,
+                length: steps - first,
+            };
+        }
+        let next = step(&state);
+        seen.insert(state, steps);
+        state = next;
+    }
+    unreachable!("a repeat is found before the steps run out")
+}
+
+/// The state after `n` steps from `start`, skipping the laps of the cycle once it repeats. Stops
+/// early without looking for a cycle if `n` comes first.
+pub fn nth_state<S: Clone + Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = start;
+    for steps in 0..n {
+        if let Some(&first) = seen.get(&state) {
+            let cycle = Cycle {
+                start: first,
+                length: steps - first,
+            };
+            return states.swap_remove(cycle.earliest_equivalent(n));
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), steps);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the Collatz step, which ends up in the cycle 4, 2, 1
+    fn step(n: u64) -> u64 {
+        if n % 2 == 0 {
+            n / 2
+        } else {
+            3 * n + 1
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(6, |&n| step(n));
+
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 6,
+                length: 3
+            }
+        );
+        assert_eq!(cycle.earliest_equivalent(4), 4);
+        assert_eq!(cycle.earliest_equivalent(1_000), 7);
+        assert_eq!(
+            find_cycle(0, |&n| n),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_nth_state() {
+        // 6, 3, 10, 5, 16, 8, 4, 2, 1, 4, ...
+        assert_eq!(nth_state(6, |&n| step(n), 0), 6);
+        assert_eq!(nth_state(6, |&n| step(n), 5), 8);
+        assert_eq!(nth_state(6, |&n| step(n), 1_000_000_000), 2);
+        assert_eq!(nth_state(6, |&n| step(n), 1_000_000_001), 1);
+    }
+}
@@ -0,0 +1,370 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::ops::Range;
+
+/// A graph over named nodes. Names are interned into dense ids as they are added, and the
+/// algorithms work on those ids. Edges are directed, [`Graph::add_undirected_edge`] adds both
+/// directions.
+#[derive(Debug, Clone, Default)]
+pub struct Graph<'a> {
+    ids: HashMap<&'a str, usize>,
+    names: Vec<&'a str>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+/// A cut through a graph: the nodes on one side of it, and how many edges cross it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    pub weight: usize,
+    pub side: Vec<usize>,
+}
+
+impl<'a> Graph<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of `name`, adding it as a node without edges if it is new.
+    pub fn intern(&mut self, name: &'a str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name, id);
+        self.names.push(name);
+        self.adjacency.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &'a str {
+        self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str) {
+        let (from, to) = (self.intern(from), self.intern(to));
+        self.adjacency[from].push(to);
+    }
+
+    pub fn add_undirected_edge(&mut self, a: &'a str, b: &'a str) {
+        self.add_edge(a, b);
+        self.add_edge(b, a);
+    }
+
+    /// The nodes `id` has an edge to, in the order the edges were added.
+    pub fn neighbors(&self, id: usize) -> &[usize] {
+        &self.adjacency[id]
+    }
+
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.adjacency[from].contains(&to)
+    }
+
+    /// Groups the nodes that are connected to each other, following edges in both directions.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut undirected = vec![vec![]; self.len()];
+        for (from, neighbors) in self.adjacency.iter().enumerate() {
+            for &to in neighbors {
+                undirected[from].push(to);
+                undirected[to].push(from);
+            }
+        }
+
+        let mut seen = vec![false; self.len()];
+        let mut components = vec![];
+        for start in self.nodes() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut component = vec![];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                component.push(node);
+                for &next in &undirected[node] {
+                    if !seen[next] {
+                        seen[next] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// Every clique that can't be grown any further, using Bron–Kerbosch with pivoting. Only
+    /// meaningful for undirected graphs.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let neighbors: Vec<HashSet<usize>> = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .map(|(id, neighbors)| neighbors.iter().copied().filter(|&n| n != id).collect())
+            .collect();
+        let mut cliques = vec![];
+        bron_kerbosch(
+            &neighbors,
+            &mut vec![],
+            self.nodes().collect(),
+            HashSet::new(),
+            &mut cliques,
+        );
+        cliques
+    }
+
+    /// The largest clique, sorted by id. Ties go to the clique found first.
+    pub fn max_clique(&self) -> Vec<usize> {
+        let mut clique = self
+            .maximal_cliques()
+            .into_iter()
+            .rev()
+            .max_by_key(Vec::len)
+            .unwrap_or_default();
+        clique.sort_unstable();
+        clique
+    }
+
+    /// The minimum cut of an undirected graph, using Stoer–Wagner. Parallel edges add up. There is
+    /// no cut with fewer than 2 nodes.
+    pub fn min_cut(&self) -> Option<Cut> {
+        let mut weights: Vec<HashMap<usize, usize>> = vec![HashMap::new(); self.len()];
+        for (from, neighbors) in self.adjacency.iter().enumerate() {
+            for &to in neighbors.iter().filter(|&&to| to != from) {
+                *weights[from].entry(to).or_default() += 1;
+            }
+        }
+        // the original nodes each merged node stands for
+        let mut merged: Vec<Vec<usize>> = self.nodes().map(|id| vec![id]).collect();
+        let mut remaining: Vec<usize> = self.nodes().collect();
+        let mut best: Option<Cut> = None;
+
+        while remaining.len() > 1 {
+            let (s, t, weight) = minimum_cut_phase(&weights, &remaining);
+            if best.as_ref().is_none_or(|best| weight < best.weight) {
+                let mut side = merged[t].clone();
+                side.sort_unstable();
+                best = Some(Cut { weight, side });
+            }
+
+            // merge t into s
+            for (n, w) in std::mem::take(&mut weights[t]) {
+                weights[n].remove(&t);
+                if n != s {
+                    *weights[s].entry(n).or_default() += w;
+                    *weights[n].entry(s).or_default() += w;
+                }
+            }
+            let nodes = std::mem::take(&mut merged[t]);
+            merged[s].extend(nodes);
+            remaining.retain(|&node| node != t);
+        }
+
+        best
+    }
+
+    /// A random cut of an undirected graph, by contracting random edges until two groups of
+    /// nodes are left (Karger). It is the minimum cut often enough to be worth repeating.
+    pub fn random_cut(&self, rng: &mut impl Rng) -> Option<Cut> {
+        if self.len() < 2 {
+            return None;
+        }
+        let mut edges: Vec<(usize, usize)> = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(from, neighbors)| neighbors.iter().map(move |&to| (from, to)))
+            .filter(|(from, to)| from < to)
+            .collect();
+        edges.shuffle(rng);
+
+        let mut groups: Vec<usize> = self.nodes().collect();
+        let mut remaining = self.len();
+        for &(a, b) in &edges {
+            if remaining == 2 {
+                break;
+            }
+            let (a, b) = (find(&mut groups, a), find(&mut groups, b));
+            if a != b {
+                groups[b] = a;
+                remaining -= 1;
+            }
+        }
+
+        let first = find(&mut groups, 0);
+        let side: Vec<usize> = self
+            .nodes()
+            .filter(|&node| find(&mut groups, node) == first)
+            .collect();
+        let weight = edges
+            .iter()
+            .filter(|&&(a, b)| find(&mut groups, a) != find(&mut groups, b))
+            .count();
+        Some(Cut { weight, side })
+    }
+}
+
+/// The group `node` was contracted into, flattening the way there.
+fn find(groups: &mut [usize], node: usize) -> usize {
+    let mut root = node;
+    while groups[root] != root {
+        root = groups[root];
+    }
+    let mut node = node;
+    while groups[node] != root {
+        (node, groups[node]) = (groups[node], root);
+    }
+    root
+}
+
+fn bron_kerbosch(
+    neighbors: &[HashSet<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: HashSet<usize>,
+    mut excluded: HashSet<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    let Some(&pivot) = candidates
+        .iter()
+        .chain(&excluded)
+        .max_by_key(|&&node| (neighbors[node].intersection(&candidates).count(), node))
+    else {
+        cliques.push(clique.clone());
+        return;
+    };
+
+    let mut branches: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|node| !neighbors[pivot].contains(node))
+        .collect();
+    // keeps the order cliques are found in the same from run to run
+    branches.sort_unstable();
+
+    for node in branches {
+        clique.push(node);
+        bron_kerbosch(
+            neighbors,
+            clique,
+            candidates.intersection(&neighbors[node]).copied().collect(),
+            excluded.intersection(&neighbors[node]).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&node);
+        excluded.insert(node);
+    }
+}
+
+/// Adds the most tightly connected node one at a time, returning the last two and the weight of
+/// the cut between the last one and everything else.
+fn minimum_cut_phase(
+    weights: &[HashMap<usize, usize>],
+    remaining: &[usize],
+) -> (usize, usize, usize) {
+    let mut connectivity = vec![0; weights.len()];
+    let mut added = vec![false; weights.len()];
+    let mut queue: BinaryHeap<(usize, usize)> = remaining.iter().map(|&n| (0, n)).collect();
+    let (mut s, mut t, mut weight) = (remaining[0], remaining[0], 0);
+
+    while let Some((w, node)) = queue.pop() {
+        if added[node] || connectivity[node] != w {
+            continue;
+        }
+        added[node] = true;
+        (s, t, weight) = (t, node, w);
+        for (&n, &edge) in &weights[node] {
+            if !added[n] {
+                connectivity[n] += edge;
+                queue.push((connectivity[n], n));
+            }
+        }
+    }
+
+    (s, t, weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn graph(edges: &str) -> Graph<'_> {
+        let mut graph = Graph::new();
+        for edge in edges.split_whitespace() {
+            let (a, b) = edge.split_once('-').unwrap();
+            graph.add_undirected_edge(a, b);
+        }
+        graph
+    }
+
+    fn names(graph: &Graph, ids: &[usize]) -> Vec<String> {
+        let mut names: Vec<String> = ids.iter().map(|&id| graph.name(id).to_string()).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_components() {
+        let graph = graph("a-b b-c d-e f-f");
+        let components = graph.components();
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(names(&graph, &components[0]), ["a", "b", "c"]);
+        assert_eq!(names(&graph, &components[1]), ["d", "e"]);
+        assert_eq!(names(&graph, &components[2]), ["f"]);
+    }
+
+    #[test]
+    fn test_cliques() {
+        // a square with one diagonal, and a tail
+        let graph = graph("a-b b-c c-d d-a a-c d-e");
+
+        assert_eq!(graph.maximal_cliques().len(), 3);
+        assert_eq!(names(&graph, &graph.max_clique()), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_min_cut() {
+        // two triangles joined by a single edge
+        let graph = graph("a-b b-c c-a d-e e-f f-d c-d");
+        let cut = graph.min_cut().unwrap();
+
+        assert_eq!(cut.weight, 1);
+        let side = names(&graph, &cut.side);
+        assert!(side == ["a", "b", "c"] || side == ["d", "e", "f"]);
+        assert_eq!(Graph::new().min_cut(), None);
+    }
+
+    #[test]
+    fn test_random_cut() {
+        let graph = graph("a-b b-c c-a d-e e-f f-d c-d");
+        let mut rng = StdRng::seed_from_u64(0);
+        let cuts: Vec<Cut> = (0..50)
+            .map(|_| graph.random_cut(&mut rng).unwrap())
+            .collect();
+
+        assert!(cuts
+            .iter()
+            .all(|cut| cut.weight >= 1 && cut.side.contains(&0)));
+        assert!(cuts.iter().any(|cut| cut.weight == 1));
+    }
+}
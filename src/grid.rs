@@ -0,0 +1,594 @@
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// The numeric types a [`Point`] can be made of.
+pub trait Coordinate:
+    Copy + Eq + Ord + Hash + Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+
+    /// Adds a signed step. Unsigned coordinates wrap around below zero, which lands them far
+    /// outside of any grid instead of panicking.
+    #[must_use]
+    fn offset(self, delta: i8) -> Self;
+
+    #[must_use]
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+
+            #[allow(clippy::cast_lossless)]
+            fn offset(self, delta: i8) -> Self {
+                self.wrapping_add(delta as Self)
+            }
+        }
+    )*};
+}
+
+coordinate!(i32, i64, isize, usize, u64);
+
+/// A point on a grid, with `y` growing downwards like the rows of a puzzle input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The 4 points sharing an edge with this one, in the order of [`Direction::ALL`].
+    pub fn neighbors(self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self + direction)
+    }
+
+    /// The 8 points surrounding this one, in the order of [`Direction8::ALL`].
+    pub fn neighbors8(self) -> [Self; 8] {
+        Direction8::ALL.map(|direction| self + direction)
+    }
+
+    fn step(self, (dx, dy): (i8, i8)) -> Self {
+        Point::new(self.x.offset(dx), self.y.offset(dy))
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// One of the 4 directions along the axes of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting from `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Parses one of `^>v<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// A step of length 1 in this direction.
+    pub fn unit<T: Coordinate + Neg<Output = T>>(self) -> Point<T> {
+        Point::new(T::ZERO, T::ZERO).step(self.delta())
+    }
+
+    fn delta(self) -> (i8, i8) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+}
+
+/// One of the 8 directions to the points surrounding a point, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise, starting from `Up`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns 45 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// A step of length 1 along each axis the direction points along.
+    pub fn unit<T: Coordinate + Neg<Output = T>>(self) -> Point<T> {
+        Point::new(T::ZERO, T::ZERO).step(self.delta())
+    }
+
+    fn delta(self) -> (i8, i8) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+impl<T: Coordinate> Add<Direction> for Point<T> {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self.step(direction.delta())
+    }
+}
+
+impl<T: Coordinate> Sub<Direction> for Point<T> {
+    type Output = Self;
+
+    fn sub(self, direction: Direction) -> Self {
+        self.step(direction.reverse().delta())
+    }
+}
+
+impl<T: Coordinate> Add<Direction8> for Point<T> {
+    type Output = Self;
+
+    fn add(self, direction: Direction8) -> Self {
+        self.step(direction.delta())
+    }
+}
+
+impl<T: Coordinate> Sub<Direction8> for Point<T> {
+    type Output = Self;
+
+    fn sub(self, direction: Direction8) -> Self {
+        self.step(direction.reverse().delta())
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Mul<T> for Direction {
+    type Output = Point<T>;
+
+    fn mul(self, rhs: T) -> Point<T> {
+        self.unit() * rhs
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Mul<T> for Direction8 {
+    type Output = Point<T>;
+
+    fn mul(self, rhs: T) -> Point<T> {
+        self.unit() * rhs
+    }
+}
+
+/// A rectangular grid, stored row by row in a single `Vec`. Points outside of the grid, including
+/// negative ones, are simply not in it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let mut grid = Grid {
+            width,
+            height,
+            cells: Vec::with_capacity(width * height),
+        };
+        for point in grid.points() {
+            grid.cells.push(f(point));
+        }
+        grid
+    }
+
+    /// Parses a character map, one row per line, turning every character into a cell with `f`.
+    ///
+    /// # Errors
+    ///
+    /// * if `f` fails for a character
+    /// * if the rows are not all equally wide
+    pub fn parse_with(s: &str, mut f: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (y, line) in s.lines().enumerate() {
+            let len = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(c).with_context(|| format!("at line {y}, column {x}"))?);
+            }
+            let row_width = cells.len() - len;
+            match width {
+                Some(width) if width != row_width => {
+                    bail!("line {y} is {row_width} wide, expected {width}")
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.width * self.height)
+            .map(move |i| Point::new((i % width) as i32, (i / width) as i32))
+    }
+
+    /// Every cell of the grid with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    /// The cells of column `x`, top to bottom. Empty if the grid has no such column.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Mirrors the grid along its diagonal, turning its columns into rows.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |point| {
+            self[Point::new(point.y, point.x)].clone()
+        })
+    }
+
+    /// The first point holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find_map(|(point, cell)| (cell == value).then_some(point))
+    }
+
+    /// Renders the grid with `mark` drawn over the cells at `points`. Chain more overlays with
+    /// [`Overlay::overlay`], later ones are drawn on top.
+    pub fn overlay(&self, points: impl IntoIterator<Item = Point>, mark: char) -> Overlay<'_, T> {
+        Overlay {
+            grid: self,
+            marks: HashMap::new(),
+        }
+        .overlay(points, mark)
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn render(&self, f: &mut Formatter<'_>, marks: &HashMap<Point, char>) -> std::fmt::Result
+    where
+        T: Display,
+    {
+        for (point, cell) in self.iter() {
+            if point.x == 0 && point.y > 0 {
+                writeln!(f)?;
+            }
+            match marks.get(&point) {
+                Some(mark) => write!(f, "{mark}")?,
+                None => write!(f, "{cell}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<anyhow::Error>,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse_with(s, |c| T::try_from(c).map_err(Into::into))
+    }
+}
+
+/// Rows are separated by newlines, without one after the last row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.render(f, &HashMap::new())
+    }
+}
+
+/// A grid with some of its cells drawn over, see [`Grid::overlay`].
+pub struct Overlay<'a, T> {
+    grid: &'a Grid<T>,
+    marks: HashMap<Point, char>,
+}
+
+impl<T> Overlay<'_, T> {
+    #[must_use]
+    pub fn overlay(mut self, points: impl IntoIterator<Item = Point>, mark: char) -> Self {
+        self.marks
+            .extend(points.into_iter().map(|point| (point, mark)));
+        self
+    }
+}
+
+impl<T: Display> Display for Overlay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.grid.render(f, &self.marks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+    }
+
+    #[test]
+    fn test_ops() {
+        let point = Point::new(3, 4);
+
+        assert_eq!(point + Direction::Up, Point::new(3, 3));
+        assert_eq!(point - Direction::Up, Point::new(3, 5));
+        assert_eq!(point + Direction8::DownLeft, Point::new(2, 5));
+        assert_eq!(point + Direction::Right * 3, Point::new(6, 4));
+        assert_eq!(point * 2 - Point::new(1, 1), Point::new(5, 7));
+        assert_eq!(point.manhattan(Point::new(0, 6)), 5);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        assert_eq!(
+            Point::new(1, 1).neighbors(),
+            [
+                Point::new(1, 0),
+                Point::new(2, 1),
+                Point::new(1, 2),
+                Point::new(0, 1)
+            ]
+        );
+        assert_eq!(Point::new(0, 0).neighbors8().len(), 8);
+        // unsigned points wrap around instead of underflowing
+        assert_eq!(
+            Point::<usize>::new(0, 0) + Direction::Left,
+            Point::new(usize::MAX, 0)
+        );
+    }
+
+    const SAMPLE: &str = "\
+#.#
+.S.";
+
+    #[test]
+    fn test_grid() {
+        let mut grid: Grid<char> = SAMPLE.parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.find(&'S'), Some(Point::new(1, 1)));
+        assert_eq!(grid.get(Point::new(2, 0)), Some(&'#'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.row(1), ['.', 'S', '.']);
+        assert_eq!(grid.column(2).collect::<String>(), "#.");
+        assert_eq!(grid.transpose().to_string(), "#.\n.S\n#.");
+
+        grid[Point::new(0, 1)] = 'O';
+        assert_eq!(grid.to_string(), "#.#\nOS.");
+        assert_eq!(
+            grid.overlay([Point::new(1, 0), Point::new(1, 1)], '|')
+                .overlay([Point::new(1, 1)], '+')
+                .to_string(),
+            "#|#\nO+."
+        );
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let grid: Grid<char> = "".parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.transpose().to_string(), "");
+        assert_eq!(grid.get(Point::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_grid_parse_errors() {
+        assert!(Grid::<char>::from_str("##\n#").is_err());
+        assert!(Grid::parse_with("12\n3x", |c| c.to_digit(10).context("not a digit")).is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::input_parsing::{Input::*, Separator::*};
+use crate::input_parsing::{
+    Input::{Path, Raw},
+    Separator::{Comma, Newline},
+};
 use itertools::Itertools;
 use std::fmt::Debug;
 use std::fs::File;
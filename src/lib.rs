@@ -2,8 +2,20 @@ pub mod _2021;
 pub mod _2023;
 pub mod _2024;
 
-#[cfg(test)]
+pub mod answers;
+pub mod client;
+pub mod cycle;
+pub mod graph;
+pub mod grid;
 pub mod input_parsing;
+pub mod math;
+pub mod puzzle;
+pub mod registry;
+pub mod search;
+pub mod solution;
+
+/// The solutions of every year. The scaffold binary keeps this in sync with the year modules.
+pub const YEARS: &[&[registry::Entry]] = &[_2021::SOLUTIONS, _2023::SOLUTIONS, _2024::SOLUTIONS];
 
 #[cfg(test)]
 #[macro_use]
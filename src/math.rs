@@ -0,0 +1,266 @@
+use anyhow::{bail, Result};
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// `None` if the result doesn't fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+pub fn gcd_of(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(0, |acc, &n| gcd(acc, n))
+}
+
+/// `None` if the result doesn't fit in a `u64`.
+pub fn lcm_of(numbers: &[u64]) -> Option<u64> {
+    numbers.iter().try_fold(1, |acc, &n| lcm(acc, n))
+}
+
+/// Returns `(g, x, y)` where `g` is the gcd of `a` and `b`, and `a * x + b * y == g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        (a.abs(), a.signum(), 0)
+    } else {
+        let (divisor, x, y) = extended_gcd(b, a % b);
+        (divisor, y, x - (a / b) * y)
+    }
+}
+
+/// The `x` in `0..modulus` where `a * x` is 1 modulo `modulus`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, with the Chinese
+/// Remainder Theorem. The moduli don't have to be coprime. Returns the smallest non-negative `x`
+/// with the lcm of the moduli, which every other solution differs by a multiple of. `None` if the
+/// congruences contradict each other, or the lcm doesn't fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, modulus), &(residue, other)| {
+            let (g, p, _) = extended_gcd(modulus, other);
+            let difference = residue - x;
+            if difference % g != 0 {
+                return None;
+            }
+            let lcm = modulus.checked_mul(other / g)?;
+            // step x by multiples of modulus until it also satisfies the new congruence
+            let steps = i128::from(difference / g) * i128::from(p) % i128::from(other / g);
+            let x = (i128::from(x) + i128::from(modulus) * steps).rem_euclid(i128::from(lcm));
+            Some((i64::try_from(x).ok()?, lcm))
+        })
+}
+
+pub fn determinant_2x2(m: [[i64; 2]; 2]) -> i64 {
+    m[0][0] * m[1][1] - m[0][1] * m[1][0]
+}
+
+pub fn determinant_3x3(m: [[i64; 3]; 3]) -> i128 {
+    let m = m.map(|row| row.map(i128::from));
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Solves `m * x == b` with Cramer's rule, if there is exactly one solution and it is whole.
+pub fn solve_2x2(matrix: [[i64; 2]; 2], rhs: [i64; 2]) -> Option<[i64; 2]> {
+    let determinant = determinant_2x2(matrix);
+    if determinant == 0 {
+        return None;
+    }
+    let mut solution = [0; 2];
+    for (column, value) in solution.iter_mut().enumerate() {
+        let mut replaced = matrix;
+        for (row, &b) in rhs.iter().enumerate() {
+            replaced[row][column] = b;
+        }
+        let numerator = determinant_2x2(replaced);
+        if numerator % determinant != 0 {
+            return None;
+        }
+        *value = numerator / determinant;
+    }
+    Some(solution)
+}
+
+/// Solves `m * x == b` with Cramer's rule, if there is exactly one solution and it is whole.
+pub fn solve_3x3(matrix: [[i64; 3]; 3], rhs: [i64; 3]) -> Option<[i64; 3]> {
+    let determinant = determinant_3x3(matrix);
+    if determinant == 0 {
+        return None;
+    }
+    let mut solution = [0; 3];
+    for (column, value) in solution.iter_mut().enumerate() {
+        let mut replaced = matrix;
+        for (row, &b) in rhs.iter().enumerate() {
+            replaced[row][column] = b;
+        }
+        let numerator = determinant_3x3(replaced);
+        if numerator % determinant != 0 {
+            return None;
+        }
+        *value = i64::try_from(numerator / determinant).ok()?;
+    }
+    Some(solution)
+}
+
+/// Primes just below 2^31, so that any two of them multiply to fit in an `i64`.
+const PRIMES: [i64; 6] = [
+    2_147_483_647,
+    2_147_483_629,
+    2_147_483_587,
+    2_147_483_579,
+    2_147_483_563,
+    2_147_483_549,
+];
+
+/// Solves `matrix * x == rhs` exactly for a whole `x`, with Gaussian elimination modulo two large
+/// primes that are combined with the Chinese Remainder Theorem. Unlike eliminating over rationals,
+/// nothing can overflow on the way, and the result is checked against the original equations.
+///
+/// # Errors
+///
+/// * if the system doesn't have exactly one solution
+/// * if the solution isn't whole, or too large for an `i64`
+pub fn solve_exact(matrix: &[Vec<i128>], rhs: &[i128]) -> Result<Vec<i64>> {
+    // a prime can divide the determinant by chance, so skip the ones the matrix is singular for
+    let solutions: Vec<(i64, Vec<i64>)> = PRIMES
+        .iter()
+        .filter_map(|&prime| solve_modulo(matrix, rhs, prime).map(|x| (prime, x)))
+        .take(2)
+        .collect();
+    let [(p, x_p), (q, x_q)] = solutions.as_slice() else {
+        bail!("the system doesn't have exactly one solution");
+    };
+
+    let solution = x_p
+        .iter()
+        .zip(x_q)
+        .map(|(&a, &b)| {
+            let (x, modulus) = crt(&[(a, *p), (b, *q)]).expect("the primes are coprime");
+            // the residues cover -modulus / 2..modulus / 2
+            if x > modulus / 2 {
+                x - modulus
+            } else {
+                x
+            }
+        })
+        .collect::<Vec<i64>>();
+
+    let satisfied = matrix.iter().zip(rhs).all(|(row, &expected)| {
+        row.iter().zip(&solution).try_fold(0i128, |sum, (&m, &x)| {
+            sum.checked_add(m.checked_mul(i128::from(x))?)
+        }) == Some(expected)
+    });
+    if !satisfied {
+        bail!("the system has no whole solution that fits in an i64");
+    }
+    Ok(solution)
+}
+
+/// Gauss-Jordan elimination modulo `prime`, `None` if the matrix is singular modulo `prime`.
+fn solve_modulo(matrix: &[Vec<i128>], rhs: &[i128], prime: i64) -> Option<Vec<i64>> {
+    let reduce = |n: i128| n.rem_euclid(i128::from(prime)) as i64;
+    let n = rhs.len();
+    let mut rows: Vec<Vec<i64>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &b)| row.iter().copied().chain([b]).map(reduce).collect())
+        .collect();
+
+    for column in 0..n {
+        let pivot = (column..n).find(|&row| rows[row][column] != 0)?;
+        rows.swap(column, pivot);
+        let inverse = mod_inverse(rows[column][column], prime)?;
+        for value in &mut rows[column] {
+            *value = *value * inverse % prime;
+        }
+        let pivot_row = rows[column].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if r == column || factor == 0 {
+                continue;
+            }
+            for (value, &pivot) in row.iter_mut().zip(&pivot_row).skip(column) {
+                *value = (*value - factor * pivot).rem_euclid(prime);
+            }
+        }
+    }
+
+    Some(rows.iter().map(|row| row[n]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd_of(&[12, 18, 8]), 2);
+        assert_eq!(lcm_of(&[2, 3, 4]), Some(12));
+        assert_eq!(lcm_of(&[]), Some(1));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // not coprime, but consistent
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        assert_eq!(crt(&[(-1, 101), (-1, 103)]), Some((10_402, 10_403)));
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve_2x2([[94, 22], [34, 67]], [8400, 5400]),
+            Some([80, 40])
+        );
+        assert_eq!(solve_2x2([[26, 67], [66, 21]], [12748, 12176]), None);
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6]), None);
+
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27
+        assert_eq!(
+            solve_3x3([[1, 1, 1], [0, 2, 5], [2, 5, -1]], [6, -4, 27]),
+            Some([5, 3, -2])
+        );
+    }
+
+    #[test]
+    fn test_solve_exact() {
+        let matrix = vec![vec![1, 1, 1], vec![0, 2, 5], vec![2, 5, -1]];
+        assert_eq!(solve_exact(&matrix, &[6, -4, 27]).unwrap(), [5, 3, -2]);
+
+        let big = 400_000_000_000_000;
+        let matrix = vec![vec![big, 3], vec![7, -big]];
+        let rhs = [big * 123_456_789 + 3 * -42, 7 * 123_456_789 - big * -42];
+        assert_eq!(solve_exact(&matrix, &rhs).unwrap(), [123_456_789, -42]);
+
+        assert!(solve_exact(&[vec![1, 2], vec![2, 4]], &[3, 6]).is_err());
+        assert!(solve_exact(&[vec![2, 0], vec![0, 2]], &[1, 2]).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use regex::Regex;
+
+/// Extracts the contents of every `<pre><code>` block in a puzzle description. The first block is
+/// usually the example input, but later ones can just as well be intermediate states or expected
+/// output, so they are only candidates.
+pub fn extract_samples(html: &str) -> Vec<String> {
+    let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").expect("valid regex");
+    let tag = Regex::new(r"<[^>]*>").expect("valid regex");
+
+    block
+        .captures_iter(html)
+        .map(|captures| {
+            let text = tag.replace_all(&captures[1], "");
+            decode_entities(text.trim_end_matches('\n'))
+        })
+        .filter(|sample| !sample.is_empty())
+        .collect()
+}
+
+/// Renders samples as `SAMPLE`, `SAMPLE_2`, ... constants, indented for the tests module of a day.
+/// Only `SAMPLE` is used by the scaffolded tests, so the others allow being dead code until a test
+/// picks them up.
+pub fn sample_consts(samples: &[String]) -> String {
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, sample)| {
+            let (attribute, name) = if i == 0 {
+                ("", "SAMPLE".to_string())
+            } else {
+                ("    #[allow(dead_code)]\n", format!("SAMPLE_{}", i + 1))
+            };
+            let escaped = sample.replace('\\', "\\\\").replace('"', "\\\"");
+            // a line continuation would swallow the leading whitespace of the sample
+            let continuation = if sample.starts_with(char::is_whitespace) {
+                ""
+            } else {
+                "\\\n"
+            };
+            format!("{attribute}    const {name}: &str = \"{continuation}{escaped}\";\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<article class="day-desc"><h2>--- Day 7: Bridge Repair ---</h2>
+<p>For example:</p>
+<pre><code>190: 10 19
+3267: 81 40 27
+</code></pre>
+<p>Only <code>3</code> of the equations can be made true:</p>
+<pre><code>&lt;-- <em>a &amp; b</em> --&gt;
+</code></pre>
+<pre><code></code></pre>
+</article>"#;
+
+    #[test]
+    fn test_extract_samples() {
+        let samples = extract_samples(SAMPLE);
+
+        assert_eq!(samples, vec!["190: 10 19\n3267: 81 40 27", "<-- a & b -->"]);
+    }
+
+    #[test]
+    fn test_sample_consts() {
+        let samples = ["1\n2".to_string(), " \"a\\b\"".to_string()];
+
+        assert_eq!(
+            sample_consts(&samples),
+            "    const SAMPLE: &str = \"\\\n1\n2\";\n\n    #[allow(dead_code)]\n    const SAMPLE_2: &str = \" \\\"a\\\\b\\\"\";\n"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::solution::Solution;
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// A single part of a day's puzzle. Takes the raw puzzle input and returns the answer.
+pub type Part = fn(&str) -> Result<Answer>;
+
+/// The answer to a single part, along with how long it took to parse the input and solve.
+pub struct Answer {
+    pub value: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+#[derive(Copy, Clone)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part_1: Part,
+    pub part_2: Part,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            part_1: run_part_1::<S>,
+            part_2: run_part_2::<S>,
+        }
+    }
+
+    /// Runs the requested part against `input`.
+    ///
+    /// # Errors
+    ///
+    /// * if `part` is not 1 or 2
+    /// * if the part is [`Unsolved`](crate::solution::Unsolved)
+    /// * if the solution itself fails to parse the input
+    pub fn run(&self, part: u8, input: &str) -> Result<Answer> {
+        match part {
+            1 => (self.part_1)(input),
+            2 => (self.part_2)(input),
+            _ => Err(anyhow!("part must be 1 or 2, got {part}")),
+        }
+    }
+}
+
+fn run_part_1<S: Solution>(input: &str) -> Result<Answer> {
+    timed(
+        || S::parse(input),
+        |parsed| Ok(S::part1(parsed)?.to_string()),
+    )
+}
+
+fn run_part_2<S: Solution>(input: &str) -> Result<Answer> {
+    timed(
+        || S::parse(input),
+        |parsed| Ok(S::part2(parsed)?.to_string()),
+    )
+}
+
+fn timed<I>(
+    parse: impl FnOnce() -> Result<I>,
+    solve: impl FnOnce(I) -> Result<String>,
+) -> Result<Answer> {
+    let start = Instant::now();
+    let parsed = parse()?;
+    let parse_time = start.elapsed();
+    let start = Instant::now();
+    let value = solve(parsed)?;
+    Ok(Answer {
+        value,
+        parse_time,
+        solve_time: start.elapsed(),
+    })
+}
+
+/// Every registered solution, ordered by year then day.
+pub fn solutions() -> impl Iterator<Item = Entry> {
+    crate::YEARS.iter().copied().flatten().copied()
+}
+
+pub fn find(year: u16, day: u8) -> Option<Entry> {
+    solutions().find(|entry| entry.year == year && entry.day == day)
+}
+
+/// The conventional location of a puzzle input, as written by the scaffold binary.
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{year}/{day:02}.txt"))
+}
+
+/// Reads a puzzle input from disk, trimming the trailing newline that `AoC` includes in every input.
+///
+/// # Errors
+///
+/// * if the file does not exist or is not valid UTF-8
+pub fn read_input(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map(|input| input.trim_end().to_string())
+        .with_context(|| format!("failed to read input {}", path.display()))
+}
@@ -0,0 +1,392 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The costs a search can add up. `Default` is the cost of not moving at all.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// A cheapest path, from the start state up to and including the goal state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Every cheapest path of a search, as the goals that were reached and the predecessors of each
+/// state on the way there that are part of a cheapest path to it. Following the predecessors back
+/// from the goals walks a DAG that ends at the start.
+#[derive(Debug, Clone)]
+pub struct AllPaths<S, C> {
+    pub cost: C,
+    pub goals: Vec<S>,
+    pub predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C> AllPaths<S, C> {
+    /// Every state that is on at least one of the cheapest paths.
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            for predecessor in self.predecessors.get(&state).into_iter().flatten() {
+                if seen.insert(predecessor.clone()) {
+                    stack.push(predecessor.clone());
+                }
+            }
+        }
+        seen
+    }
+
+    /// How many different cheapest paths there are, to any of the goals.
+    pub fn count(&self) -> u64 {
+        let mut counts: HashMap<&S, u64> = HashMap::new();
+        let mut stack: Vec<(&S, bool)> = self.goals.iter().map(|goal| (goal, false)).collect();
+        while let Some((state, expanded)) = stack.pop() {
+            if counts.contains_key(state) {
+                continue;
+            }
+            let predecessors = self.predecessors_of(state);
+            if predecessors.is_empty() {
+                counts.insert(state, 1);
+            } else if expanded {
+                let count = predecessors.iter().map(|p| counts[p]).sum();
+                counts.insert(state, count);
+            } else {
+                // count the predecessors first, then come back to this one
+                stack.push((state, true));
+                stack.extend(predecessors.iter().map(|p| (p, false)));
+            }
+        }
+        self.goals.iter().map(|goal| counts[goal]).sum()
+    }
+
+    /// Lazily lists every cheapest path, from the start to a goal.
+    pub fn paths(&self) -> impl Iterator<Item = Vec<S>> + '_ {
+        let mut stack: Vec<Vec<S>> = self.goals.iter().map(|goal| vec![goal.clone()]).collect();
+        std::iter::from_fn(move || {
+            while let Some(mut path) = stack.pop() {
+                let predecessors = self.predecessors_of(path.last()?);
+                if predecessors.is_empty() {
+                    path.reverse();
+                    return Some(path);
+                }
+                for predecessor in predecessors {
+                    let mut longer = path.clone();
+                    longer.push(predecessor.clone());
+                    stack.push(longer);
+                }
+            }
+            None
+        })
+    }
+
+    fn predecessors_of(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Breadth first search, where every step costs 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut predecessors = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+    let mut seen = HashSet::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            let states = reconstruct(state, |state| predecessors.get(state).cloned());
+            return Some(Path {
+                cost: states.len() - 1,
+                states,
+            });
+        }
+        for next in neighbors(&state) {
+            if seen.insert(next.clone()) {
+                predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, `neighbors` yields the next states with the cost of moving to them.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search. The `heuristic` must never overestimate the remaining cost to a goal, and must not
+/// drop by more than the cost of a step, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let found = search(start, neighbors, heuristic, is_goal, false)?;
+    let goal = found.goals.into_iter().next()?;
+    let states = reconstruct(goal, |state| {
+        found
+            .predecessors
+            .get(state)
+            .and_then(|predecessors| predecessors.first().cloned())
+    });
+    Some(Path {
+        cost: found.cost,
+        states,
+    })
+}
+
+/// Dijkstra's algorithm, keeping every cheapest path and every goal reached at the lowest cost.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, neighbors, |_| C::default(), is_goal, true)
+}
+
+fn search<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> Option<AllPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut frontier = BinaryHeap::new();
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut found: Option<AllPaths<S, C>> = None;
+
+    frontier.push(Work {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    });
+
+    while let Some(Work {
+        priority,
+        cost,
+        state,
+    }) = frontier.pop()
+    {
+        if found.as_ref().is_some_and(|found| priority > found.cost) {
+            break;
+        }
+        // a cheaper way here was found after this one was queued
+        if costs.get(&state).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if is_goal(&state) {
+            found
+                .get_or_insert_with(|| AllPaths {
+                    cost,
+                    goals: vec![],
+                    predecessors: HashMap::new(),
+                })
+                .goals
+                .push(state);
+            if all {
+                continue;
+            }
+            break;
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            match costs.get(&next).map(|&best| next_cost.cmp(&best)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => {
+                    if all {
+                        predecessors.entry(next).or_default().push(state.clone());
+                    }
+                }
+                Some(Ordering::Less) | None => {
+                    costs.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    frontier.push(Work {
+                        priority: next_cost + heuristic(&next),
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    found.map(|found| AllPaths {
+        predecessors,
+        ..found
+    })
+}
+
+/// Walks back from `goal` to the state without a predecessor, returning the states in order.
+fn reconstruct<S>(goal: S, mut predecessor: impl FnMut(&S) -> Option<S>) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(previous) = states.last().and_then(&mut predecessor) {
+        states.push(previous);
+    }
+    states.reverse();
+    states
+}
+
+struct Work<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+// Flip the ordering to make BinaryHeap a min-heap, preferring the most progress on ties
+impl<S, C: Ord> Ord for Work<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Work<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Work<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Work<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Direction, Grid, Point};
+
+    const SAMPLE: &str = "\
+S..#
+.#.#
+...E";
+
+    fn open_neighbors(grid: &Grid<char>, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors()
+            .into_iter()
+            .filter(|&next| grid.get(next).is_some_and(|&c| c != '#'))
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid: Grid<char> = SAMPLE.parse().unwrap();
+        let end = grid.find(&'E').unwrap();
+        let path = bfs(
+            Point::new(0, 0),
+            |&p| open_neighbors(&grid, p),
+            |&p| p == end,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 6);
+        assert_eq!(path.states.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.states.last(), Some(&end));
+        assert!(bfs(Point::new(0, 0), |&p| open_neighbors(&grid, p), |_| false).is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // the left column is expensive, so going right first is cheaper
+        let grid: Grid<char> = SAMPLE.parse().unwrap();
+        let end = grid.find(&'E').unwrap();
+        let neighbors = |&p: &Point| {
+            open_neighbors(&grid, p)
+                .map(move |next| (next, if next.x == 0 { 10 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra(Point::new(0, 0), neighbors, |&p| p == end).unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states[1], Point::new(1, 0));
+
+        let path = astar(
+            Point::new(0, 0),
+            neighbors,
+            |p| p.manhattan(end),
+            |&p| p == end,
+        );
+        assert_eq!(path.unwrap().cost, 5);
+    }
+
+    #[test]
+    fn test_count_open_field() {
+        // every way of mixing 3 steps right with 3 steps down
+        let paths = dijkstra_all(
+            Point::new(0, 0),
+            |&p: &Point| [(p + Direction::Right, 1), (p + Direction::Down, 1)],
+            |&p| p == Point::new(3, 3),
+        )
+        .unwrap();
+
+        assert_eq!(paths.count(), 20);
+        assert_eq!(paths.paths().count(), 20);
+        assert_eq!(paths.states().len(), 16);
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        let grid: Grid<char> = SAMPLE.parse().unwrap();
+        let end = grid.find(&'E').unwrap();
+        let neighbors = |&p: &Point| open_neighbors(&grid, p).map(|next| (next, 1));
+
+        let paths = dijkstra_all(Point::new(0, 0), neighbors, |&p| p == end).unwrap();
+        assert_eq!(paths.cost, 5);
+        assert_eq!(paths.goals, [end]);
+        // both ways around the pillar in the middle
+        assert_eq!(paths.states().len(), 9);
+        assert_eq!(paths.count(), 2);
+
+        let mut all = paths.paths().collect::<Vec<_>>();
+        all.sort();
+        assert_eq!(all.len(), 2);
+        assert!(all.iter().all(|path| path.len() == 6 && path[5] == end));
+        assert_eq!(all[0][1], Point::new(0, 1));
+        assert_eq!(all[1][1], Point::new(1, 0));
+    }
+}
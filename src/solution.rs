@@ -0,0 +1,32 @@
+use anyhow::Result;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A single day's puzzle. Parsing is kept separate from solving so that tooling can time the two
+/// independently, and each part receives its own freshly parsed input so it is free to mutate it.
+pub trait Solution {
+    /// The parsed puzzle input. May borrow from the raw input.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    fn part1(input: Self::Input<'_>) -> Result<impl Display>;
+
+    /// Fails with [`Unsolved`] unless overridden, for puzzles whose second part has not been
+    /// solved yet, or that have no second part at all (day 25).
+    fn part2(_input: Self::Input<'_>) -> Result<impl Display> {
+        Err::<String, _>(Unsolved.into())
+    }
+}
+
+/// The error returned by a part that has no solution.
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not solved")
+    }
+}
+
+impl Error for Unsolved {}